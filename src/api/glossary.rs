@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, HashMap};
+use crate::api::models::{Character, Language, SystemView};
+
+/// Somewhere a character is used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Occurrence {
+    /// As the term at a position (1-based)
+    Term { order: i32, position: i32 },
+    /// As the connective from one position to another (1-based)
+    Connective { order: i32, base: i32, target: i32 },
+}
+
+impl Occurrence {
+    pub fn order(&self) -> i32 {
+        match self {
+            Occurrence::Term { order, .. } | Occurrence::Connective { order, .. } => *order,
+        }
+    }
+}
+
+/// A character and every place it occurs
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlossaryEntry {
+    pub character: Character,
+    pub occurrences: Vec<Occurrence>,
+}
+
+/// Reverse index from character id to its uses across the loaded systems
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Glossary {
+    entries: Vec<GlossaryEntry>,
+}
+
+impl Glossary {
+    /// Index every term and connective character in `systems`
+    pub fn build(systems: &[SystemView]) -> Self {
+        let mut entries: Vec<GlossaryEntry> = Vec::new();
+        let mut by_id: HashMap<String, usize> = HashMap::new();
+
+        let mut record = |character: &Character, occurrence: Occurrence| {
+            let index = *by_id.entry(character.id.clone()).or_insert_with(|| {
+                entries.push(GlossaryEntry { character: character.clone(), occurrences: vec![] });
+                entries.len() - 1
+            });
+            entries[index].occurrences.push(occurrence);
+        };
+
+        for system in systems {
+            for term in &system.terms {
+                if let Some(ref character) = term.character {
+                    record(character, Occurrence::Term { order: system.order, position: term.position });
+                }
+            }
+            for conn in &system.connectives {
+                if let (Some(character), Some(base), Some(target)) =
+                    (conn.character.as_ref(), conn.base_position, conn.target_position)
+                {
+                    record(character, Occurrence::Connective { order: system.order, base, target });
+                }
+            }
+        }
+
+        for entry in entries.iter_mut() {
+            entry.occurrences.sort_by_key(|o| o.order());
+        }
+        Self { entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries grouped by language, each group sorted by value
+    pub fn by_language(&self) -> BTreeMap<Language, Vec<&GlossaryEntry>> {
+        let mut groups: BTreeMap<Language, Vec<&GlossaryEntry>> = BTreeMap::new();
+        for entry in &self.entries {
+            groups.entry(entry.character.language).or_default().push(entry);
+        }
+        for group in groups.values_mut() {
+            group.sort_by_key(|entry| entry.character.value.to_lowercase());
+        }
+        groups
+    }
+}
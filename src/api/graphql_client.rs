@@ -1,6 +1,8 @@
 use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
-use crate::api::models::{SystemView, ApiError, Coordinate};
+use crate::api::models::{SystemView, ApiError, Coordinate, Slice};
+use crate::core::geometry::{GeometryCalculator, Point, Similarity};
+use crate::core::system_kind::SystemKind;
 
 /// GraphQL request structure
 #[derive(Serialize)]
@@ -28,13 +30,6 @@ struct SystemQueryResponse {
     system: Option<SystemView>,
 }
 
-/// System by name query response
-#[derive(Deserialize, Debug)]
-struct SystemByNameQueryResponse {
-    #[serde(rename = "systemByName")]
-    system_by_name: Option<SystemView>,
-}
-
 /// All systems query response
 #[derive(Deserialize, Debug)]
 struct AllSystemsQueryResponse {
@@ -42,6 +37,12 @@ struct AllSystemsQueryResponse {
     all_systems: Vec<SystemView>,
 }
 
+/// Slice query response (for slice(position:) query)
+#[derive(Deserialize, Debug)]
+struct SliceQueryResponse {
+    slice: Vec<Slice>,
+}
+
 /// GraphQL API client for systematics data
 #[derive(Clone)]
 pub struct GraphQLClient {
@@ -148,7 +149,7 @@ impl GraphQLClient {
         }
     "#;
 
-    /// Fetch a single system by order (1-12)
+    /// Fetch a single system by order
     pub async fn fetch_system_by_order(&self, order: i32) -> Result<SystemView, ApiError> {
         let query = format!(r#"
             query GetSystem($order: Int!) {{
@@ -180,39 +181,7 @@ impl GraphQLClient {
         Ok(self.transform_coordinates(system))
     }
 
-    /// Fetch a single system by name (uses systemByName API query)
-    pub async fn fetch_system(&self, system_name: &str) -> Result<SystemView, ApiError> {
-        let query = format!(r#"
-            query GetSystemByName($name: String!) {{
-                systemByName(name: $name) {{
-                    {}
-                }}
-            }}
-        "#, Self::SYSTEM_FIELDS);
-
-        let variables = serde_json::json!({
-            "name": system_name
-        });
-
-        let response: GraphQLResponse<SystemByNameQueryResponse> =
-            self.execute_query(&query, Some(variables)).await?;
-
-        if let Some(errors) = response.errors {
-            return Err(ApiError::ParseError(
-                errors.iter().map(|e| e.message.clone()).collect::<Vec<_>>().join(", ")
-            ));
-        }
-
-        let data = response.data
-            .ok_or_else(|| ApiError::NotFound(format!("System '{}' not found", system_name)))?;
-
-        let system = data.system_by_name
-            .ok_or_else(|| ApiError::NotFound(format!("System '{}' not found", system_name)))?;
-
-        Ok(self.transform_coordinates(system))
-    }
-
-    /// Fetch all available systems (orders 1-12)
+    /// Fetch all available systems (orders 1-12, plus any higher orders the backend provides)
     pub async fn fetch_all_systems(&self) -> Result<Vec<SystemView>, ApiError> {
         let query = format!(r#"
             query GetAllSystems {{
@@ -247,6 +216,67 @@ impl GraphQLClient {
         Ok(systems)
     }
 
+    /// Fetch the entries at one position across every order (uses the slice API query).
+    /// Backends without a `slice` query answer with an error, which callers can treat
+    /// as a cue to cut the slice from `allSystems` instead.
+    pub async fn fetch_slice(&self, position: i32) -> Result<Vec<Slice>, ApiError> {
+        let query = r#"
+            query GetSlice($position: Int!) {
+                slice(position: $position) {
+                    order
+                    position
+                    term {
+                        id
+                        order
+                        position
+                        characterId
+                        character {
+                            id
+                            language
+                            value
+                        }
+                    }
+                    coordinate {
+                        id
+                        order
+                        position
+                        x
+                        y
+                        z
+                    }
+                    colour {
+                        id
+                        order
+                        position
+                        language
+                        value
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "position": position
+        });
+
+        let response: GraphQLResponse<SliceQueryResponse> =
+            self.execute_query(query, Some(variables)).await?;
+
+        if let Some(errors) = response.errors {
+            let message = errors.iter().map(|e| e.message.clone()).collect::<Vec<_>>().join(", ");
+            return Err(if errors.iter().any(|e| is_schema_error(&e.message)) {
+                ApiError::Unsupported(message)
+            } else {
+                ApiError::ParseError(message)
+            });
+        }
+
+        let data = response.data
+            .ok_or_else(|| ApiError::NotFound(format!("No slice at position {}", position)))?;
+
+        Ok(data.slice)
+    }
+
     /// Execute a GraphQL query
     async fn execute_query<T: for<'de> Deserialize<'de>>(
         &self,
@@ -281,23 +311,99 @@ impl GraphQLClient {
 
     /// Transform coordinates from API space to viewport space (800x800 with margins)
     fn transform_coordinates(&self, mut system: SystemView) -> SystemView {
-        let viewport_width = 800.0;
-        let viewport_height = 800.0;
-        let margin = 100.0;
-
-        // Transform main coordinates array only
-        // Links will look up coordinates by position from this array
-        system.coordinates = transform_coordinates_to_viewport(
-            system.coordinates,
-            viewport_width,
-            viewport_height,
-            margin,
-        );
-
+        system.coordinates = viewport_coordinates(system.coordinates, system.order);
         system
     }
 }
 
+/// Complete a system's coordinates and bring them into viewport space
+///
+/// Missing positions are filled in API space first, so the viewport transform sees the
+/// whole system and keeps every node, provided or synthesized, inside the margins.
+/// Links will look up coordinates by position from this array.
+fn viewport_coordinates(coords: Vec<Coordinate>, order: i32) -> Vec<Coordinate> {
+    let viewport_width = 800.0;
+    let viewport_height = 800.0;
+    let margin = 100.0;
+
+    transform_coordinates_to_viewport(
+        fill_missing_coordinates(coords, order),
+        viewport_width,
+        viewport_height,
+        margin,
+    )
+}
+
+/// Whether a GraphQL error says the schema lacks a field or argument, as older
+/// backends without the `slice` query answer
+fn is_schema_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["cannot query field", "unknown field", "unknown argument"]
+        .iter()
+        .any(|phrase| message.contains(phrase))
+}
+
+/// Fill in coordinates for positions the API left out, so incomplete backend
+/// data still produces a usable diagram.
+///
+/// Missing positions take their place from the procedural layout for the system
+/// and are marked as synthesized so the view can distinguish them. The procedural
+/// layout is first rotated, scaled and moved onto the provided positions (or just
+/// moved, when only one was provided), so the synthesized ones share their frame.
+/// This works in API space, where y points up.
+fn fill_missing_coordinates(mut coords: Vec<Coordinate>, order: i32) -> Vec<Coordinate> {
+    let Some(kind) = SystemKind::from_order(order.max(0) as usize) else {
+        return coords;
+    };
+    let missing: Vec<i32> = (1..=order)
+        .filter(|position| !coords.iter().any(|c| c.position == *position))
+        .collect();
+
+    if missing.is_empty() {
+        return coords;
+    }
+
+    // The procedural layout is in SVG space, so flip it to y-up to match the API
+    let layout: Vec<Point> = GeometryCalculator::calculate_system_layout(kind, 0.0, 0.0, 700.0)
+        .nodes
+        .into_iter()
+        .map(|node| Point { x: node.x, y: -node.y })
+        .collect();
+
+    let (procedural, provided): (Vec<Point>, Vec<Point>) = coords.iter()
+        .filter_map(|c| {
+            let node = layout.get((c.position - 1) as usize)?;
+            Some((node.clone(), Point { x: c.x, y: c.y }))
+        })
+        .unzip();
+    let alignment = Similarity::fit(&procedural, &provided).or_else(|| match (&procedural[..], &provided[..]) {
+        ([from], [to]) => Some(Similarity::translation(from, to)),
+        _ => None,
+    });
+
+    for position in missing {
+        let Some(node) = layout.get((position - 1) as usize) else {
+            continue;
+        };
+        let point = match alignment {
+            Some(ref alignment) => alignment.apply(node),
+            None => node.clone(),
+        };
+        coords.push(Coordinate {
+            id: format!("synthesized-{}-{}", order, position),
+            order,
+            position,
+            x: point.x,
+            y: point.y,
+            z: 0.0,
+            synthesized: true,
+        });
+    }
+
+    coords.sort_by_key(|c| c.position);
+    coords
+}
+
 /// Transform coordinates from API space to viewport space
 ///
 /// The API may return coordinates in any scale (e.g., 0-1, 0-10, or even 0,0,0 for single points).
@@ -371,3 +477,76 @@ fn transform_coordinates_to_viewport(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provided(order: i32, points: &[(i32, f64, f64)]) -> Vec<Coordinate> {
+        points.iter()
+            .map(|&(position, x, y)| Coordinate {
+                id: format!("{}-{}", order, position),
+                order,
+                position,
+                x,
+                y,
+                z: 0.0,
+                synthesized: false,
+            })
+            .collect()
+    }
+
+    fn assert_inside_viewport(coords: &[Coordinate], order: i32) {
+        assert_eq!(coords.len(), order as usize);
+        for c in coords {
+            assert!((100.0 - 1e-6..=700.0 + 1e-6).contains(&c.x), "position {} x = {}", c.position, c.x);
+            assert!((100.0 - 1e-6..=700.0 + 1e-6).contains(&c.y), "position {} y = {}", c.position, c.y);
+        }
+    }
+
+    fn min_separation(coords: &[Coordinate]) -> f64 {
+        let mut min = f64::INFINITY;
+        for (i, a) in coords.iter().enumerate() {
+            for b in &coords[i + 1..] {
+                min = min.min((a.x - b.x).hypot(a.y - b.y));
+            }
+        }
+        min
+    }
+
+    #[test]
+    fn partial_systems_stay_inside_the_viewport() {
+        for order in 3..=12 {
+            let coords = viewport_coordinates(provided(order, &[(1, 0.0, 1.0), (2, 0.5, 0.8)]), order);
+            assert_inside_viewport(&coords, order);
+            assert!(min_separation(&coords) > 20.0, "K{} nodes overlap", order);
+            assert_eq!(coords.iter().filter(|c| c.synthesized).count(), order as usize - 2);
+        }
+    }
+
+    #[test]
+    fn a_single_provided_point_keeps_its_own_slot() {
+        for order in 2..=12 {
+            let coords = viewport_coordinates(provided(order, &[(3.min(order), 5.0, 5.0)]), order);
+            assert_inside_viewport(&coords, order);
+            assert!(min_separation(&coords) > 20.0, "K{} nodes overlap", order);
+        }
+    }
+
+    #[test]
+    fn missing_coordinates_are_laid_out_procedurally() {
+        let coords = viewport_coordinates(vec![], 6);
+        assert_inside_viewport(&coords, 6);
+        assert!(coords.iter().all(|c| c.synthesized));
+    }
+
+    #[test]
+    fn complete_systems_are_only_transformed() {
+        let square = [(1, 0.0, 1.0), (2, 1.0, 0.0), (3, 0.0, -1.0), (4, -1.0, 0.0)];
+        let coords = viewport_coordinates(provided(4, &square), 4);
+        assert_inside_viewport(&coords, 4);
+        assert!(coords.iter().all(|c| !c.synthesized));
+        // y points up in the API and down in SVG
+        assert!(coords[0].y < coords[2].y);
+    }
+}
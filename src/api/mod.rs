@@ -1,2 +1,3 @@
 pub mod models;
 pub mod graphql_client;
+pub mod glossary;
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use crate::core::palette::{Palette, PaletteMode};
+use crate::core::symmetry::Permutation;
+use crate::core::system_kind::SystemKind;
 
 /// Language enum matching GqlLanguage from backend
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Language {
     Canonical,
@@ -12,6 +15,44 @@ pub enum Language {
     Name,
 }
 
+impl Language {
+    pub const ALL: [Language; 6] = [
+        Language::Canonical,
+        Language::Energy,
+        Language::Values,
+        Language::Society,
+        Language::Hex,
+        Language::Name,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::Canonical => "Canonical",
+            Language::Energy => "Energy",
+            Language::Values => "Values",
+            Language::Society => "Society",
+            Language::Hex => "Hex",
+            Language::Name => "Name",
+        }
+    }
+
+    /// Stable key for storage and select values
+    pub fn key(&self) -> &'static str {
+        match self {
+            Language::Canonical => "canonical",
+            Language::Energy => "energy",
+            Language::Values => "values",
+            Language::Society => "society",
+            Language::Hex => "hex",
+            Language::Name => "name",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Language> {
+        Self::ALL.into_iter().find(|language| language.key() == key)
+    }
+}
+
 /// Link type enum matching GqlLinkType from backend
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -48,6 +89,9 @@ pub struct Coordinate {
     pub x: f64,
     pub y: f64,
     pub z: f64,
+    /// Whether this coordinate was laid out locally because the API didn't provide it
+    #[serde(default)]
+    pub synthesized: bool,
 }
 
 /// Colour matching GqlColour from backend
@@ -109,24 +153,17 @@ pub struct SystemView {
 }
 
 impl SystemView {
+    /// Get the typed system kind for this system's order
+    pub fn kind(&self) -> Option<SystemKind> {
+        SystemKind::from_order(self.order.max(0) as usize)
+    }
+
     /// Get the system name, falling back to order-based name
     pub fn display_name(&self) -> String {
         self.name.clone().unwrap_or_else(|| {
-            match self.order {
-                1 => "Monad",
-                2 => "Dyad",
-                3 => "Triad",
-                4 => "Tetrad",
-                5 => "Pentad",
-                6 => "Hexad",
-                7 => "Heptad",
-                8 => "Octad",
-                9 => "Ennead",
-                10 => "Decad",
-                11 => "Undecad",
-                12 => "Dodecad",
-                _ => "Unknown",
-            }.to_string()
+            self.kind()
+                .map(|kind| kind.display_name())
+                .unwrap_or_else(|| "Unknown".to_string())
         })
     }
 
@@ -140,6 +177,16 @@ impl SystemView {
         self.coherence.clone().unwrap_or_else(|| self.display_name())
     }
 
+    /// Get the title for the term (node) legend, preferring the term designation
+    pub fn term_legend_title(&self) -> String {
+        self.term_designation.clone().unwrap_or_else(|| "Terms".to_string())
+    }
+
+    /// Get the title for the connective (edge) legend, preferring the connective designation
+    pub fn connective_legend_title(&self) -> String {
+        self.connective_designation.clone().unwrap_or_else(|| "Connectives".to_string())
+    }
+
     /// Get the number of nodes in this system
     pub fn node_count(&self) -> usize {
         self.order as usize
@@ -160,6 +207,187 @@ impl SystemView {
             .map(|c| c.value.as_str())
     }
 
+    /// Get the connective label from one position to another (1-based), in that direction
+    pub fn connective_label(&self, base: i32, target: i32) -> Option<&str> {
+        self.connectives.iter()
+            .find(|c| c.base_position == Some(base) && c.target_position == Some(target))
+            .and_then(|c| c.character.as_ref())
+            .map(|c| c.value.as_str())
+    }
+
+    /// Copy of this system with its terms (and their colours) moved to new positions.
+    /// Coordinates, lines and connectives stay attached to the positions.
+    pub fn with_permuted_terms(&self, permutation: &Permutation) -> SystemView {
+        let moved = |position: i32| permutation.apply((position - 1) as usize) as i32 + 1;
+
+        let mut permuted = self.clone();
+        for term in permuted.terms.iter_mut() {
+            term.position = moved(term.position);
+        }
+        for colour in permuted.colours.iter_mut() {
+            colour.position = moved(colour.position);
+        }
+        permuted
+    }
+
+    /// Languages the colours are given in
+    pub fn colour_languages(&self) -> std::collections::BTreeSet<Language> {
+        self.colours.iter().map(|c| c.language).collect()
+    }
+
+    /// Copy of this system with one colour per position: the one in `language` if there is
+    /// one, then a hex colour, then whichever the API listed first
+    pub fn with_colour_language(&self, language: Language) -> SystemView {
+        SystemView {
+            colours: self.colours_in(language).into_iter().cloned().collect(),
+            ..self.clone()
+        }
+    }
+
+    /// One colour per position, chosen as in `with_colour_language`, in the order the API
+    /// first lists the positions
+    fn colours_in(&self, language: Language) -> Vec<&Colour> {
+        let rank = |colour: &Colour| {
+            if colour.language == language { 0 } else if colour.language == Language::Hex { 1 } else { 2 }
+        };
+
+        let mut chosen: Vec<&Colour> = Vec::new();
+        let mut slots: std::collections::HashMap<i32, usize> = std::collections::HashMap::new();
+        for colour in &self.colours {
+            match slots.get(&colour.position) {
+                Some(&slot) if rank(colour) < rank(chosen[slot]) => chosen[slot] = colour,
+                Some(_) => {}
+                None => {
+                    slots.insert(colour.position, chosen.len());
+                    chosen.push(colour);
+                }
+            }
+        }
+        chosen
+    }
+
+    /// Copy of this system with `value` at every position that has no colour
+    pub fn with_default_colour(&self, value: &str) -> SystemView {
+        let mut filled = self.clone();
+        let coloured: std::collections::HashSet<i32> = self.colours.iter().map(|c| c.position).collect();
+        for position in 1..=self.order {
+            if !coloured.contains(&position) {
+                filled.colours.push(Colour {
+                    id: format!("default-{}", position),
+                    order: self.order,
+                    position,
+                    language: Language::Hex,
+                    value: value.to_string(),
+                });
+            }
+        }
+        filled
+    }
+
+    /// Copy of this system with its colours taken from `palette`. Colours the palette
+    /// adds are tagged with the canonical language.
+    pub fn with_palette(&self, palette: &Palette) -> SystemView {
+        let mut painted = self.clone();
+        if palette.colour(0).is_none() {
+            return painted;
+        }
+
+        match palette.mode {
+            PaletteMode::FillGaps => {}
+            PaletteMode::Remap => {
+                let mut distinct: Vec<String> = Vec::new();
+                for colour in painted.colours.iter_mut() {
+                    let index = distinct.iter().position(|value| *value == colour.value)
+                        .unwrap_or_else(|| {
+                            distinct.push(colour.value.clone());
+                            distinct.len() - 1
+                        });
+                    colour.value = palette.colour(index).unwrap_or_default().to_string();
+                }
+            }
+            PaletteMode::Override => painted.colours.clear(),
+        }
+
+        let coloured: std::collections::HashSet<i32> = painted.colours.iter().map(|c| c.position).collect();
+        for position in 1..=self.order {
+            if !coloured.contains(&position) {
+                painted.colours.push(Colour {
+                    id: format!("palette-{}", position),
+                    order: self.order,
+                    position,
+                    language: Language::Canonical,
+                    value: palette.colour((position - 1) as usize).unwrap_or_default().to_string(),
+                });
+            }
+        }
+        painted
+    }
+
+    /// For each connective, compare the label between its two terms before and after
+    /// the terms are permuted
+    pub fn connective_changes(&self, permutation: &Permutation) -> Vec<ConnectiveChange> {
+        let moved = |position: i32| permutation.apply((position - 1) as usize) as i32 + 1;
+
+        self.connectives.iter()
+            .filter_map(|conn| {
+                let base = conn.base_position?;
+                let target = conn.target_position?;
+                let before = conn.character.as_ref()?.value.clone();
+                let after = self.connective_label(moved(base), moved(target))
+                    .unwrap_or("")
+                    .to_string();
+
+                Some(ConnectiveChange {
+                    base_term: self.term_at(base).map(str::to_string).unwrap_or_else(|| base.to_string()),
+                    target_term: self.term_at(target).map(str::to_string).unwrap_or_else(|| target.to_string()),
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+
+    /// Distinct term characters in this system
+    pub fn term_values(&self) -> std::collections::BTreeSet<&str> {
+        self.terms.iter()
+            .filter_map(|t| t.character.as_ref())
+            .map(|c| c.value.as_str())
+            .collect()
+    }
+
+    /// Distinct connective characters in this system
+    pub fn connective_values(&self) -> std::collections::BTreeSet<&str> {
+        self.connectives.iter()
+            .filter_map(|c| c.character.as_ref())
+            .map(|c| c.value.as_str())
+            .collect()
+    }
+
+    /// Count which terms, coordinates, colours and connectives the backend provided
+    pub fn coverage(&self) -> Coverage {
+        let n = self.node_count();
+        let positions = 1..=self.order;
+
+        let pairs: std::collections::HashSet<(i32, i32)> = self.connectives.iter()
+            .filter_map(|c| {
+                let (base, target) = (c.base_position?, c.target_position?);
+                (base != target).then_some((base.min(target), base.max(target)))
+            })
+            .collect();
+
+        Coverage {
+            terms: (positions.clone().filter(|&p| self.term_at(p).is_some()).count(), n),
+            coordinates: (
+                positions.clone()
+                    .filter(|&p| self.coordinate_at(p).is_some_and(|c| !c.synthesized))
+                    .count(),
+                n,
+            ),
+            colours: (positions.filter(|&p| self.colour_at(p).is_some()).count(), n),
+            connectives: (pairs.len(), n * n.saturating_sub(1) / 2),
+        }
+    }
+
     /// Get the coordinate at a position (1-based)
     pub fn coordinate_at(&self, position: i32) -> Option<&Coordinate> {
         self.coordinates.iter()
@@ -167,6 +395,45 @@ impl SystemView {
     }
 }
 
+/// How much of a system's data the backend provided, as (present, expected) per kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coverage {
+    pub terms: (usize, usize),
+    pub coordinates: (usize, usize),
+    pub colours: (usize, usize),
+    /// Pairs of positions with a connective in either direction
+    pub connectives: (usize, usize),
+}
+
+impl Coverage {
+    /// Share of everything expected that is present, from 0.0 to 1.0
+    pub fn fraction(&self) -> f64 {
+        let parts = [self.terms, self.coordinates, self.colours, self.connectives];
+        let present: usize = parts.iter().map(|p| p.0).sum();
+        let expected: usize = parts.iter().map(|p| p.1).sum();
+        if expected == 0 {
+            1.0
+        } else {
+            present as f64 / expected as f64
+        }
+    }
+}
+
+/// How the connective between two terms changes when the terms are permuted
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectiveChange {
+    pub base_term: String,
+    pub target_term: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl ConnectiveChange {
+    pub fn is_preserved(&self) -> bool {
+        self.before == self.after
+    }
+}
+
 /// Slice matching GqlSlice from backend (all entries at order+position)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Slice {
@@ -177,12 +444,32 @@ pub struct Slice {
     pub colour: Option<Colour>,
 }
 
+impl Slice {
+    /// Cut the slice at `position` out of a whole system, if the system has that position,
+    /// with the colour `with_colour_language` would give it
+    pub fn from_system(system: &SystemView, position: i32, language: Language) -> Option<Slice> {
+        if position < 1 || position > system.order {
+            return None;
+        }
+
+        Some(Slice {
+            order: system.order,
+            position,
+            term: system.terms.iter().find(|t| t.position == position).cloned(),
+            coordinate: system.coordinate_at(position).cloned(),
+            colour: system.colours_in(language).into_iter().find(|c| c.position == position).cloned(),
+        })
+    }
+}
+
 /// API error type
 #[derive(Debug)]
 pub enum ApiError {
     NetworkError(String),
     ParseError(String),
     NotFound(String),
+    /// The backend's schema doesn't offer the query or a field it asks for
+    Unsupported(String),
 }
 
 impl std::fmt::Display for ApiError {
@@ -191,6 +478,7 @@ impl std::fmt::Display for ApiError {
             ApiError::NetworkError(msg) => write!(f, "Network error: {}", msg),
             ApiError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             ApiError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            ApiError::Unsupported(msg) => write!(f, "Unsupported by the backend: {}", msg),
         }
     }
 }
@@ -1,34 +1,111 @@
+use std::collections::BTreeSet;
+use gloo_events::EventListener;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use crate::api::models::SystemView;
+use crate::api::models::{ApiError, Language, Slice, SystemView};
 use crate::api::graphql_client::GraphQLClient;
-use crate::components::api_graph_view::ApiGraphView;
+use crate::components::api_graph_view::{ApiGraphView, Focus};
+use crate::components::glossary_view::GlossaryView;
+use crate::components::search_palette::SearchPalette;
+use crate::components::shortcuts::{shortcut_for, Shortcut, ShortcutHelp};
+use crate::components::slice_browser::{SliceBrowser, SliceSource};
+use crate::components::system_comparison::SystemComparison;
+use crate::components::system_gallery::SystemGallery;
+use crate::components::system_header::SystemHeader;
 use crate::components::system_selector::SystemSelector;
-use crate::core::system_config::SystemConfig;
+use crate::core::system_config::{ColorScheme, SystemConfig};
+use crate::core::system_kind::SystemKind;
+use crate::core::geometry::LayoutKind;
+use crate::core::palette::{Palette, PaletteKind};
+use crate::core::theme::ThemeKind;
+use crate::preferences::{Preferences, Session};
 
-#[derive(Clone, Debug, PartialEq)]
+/// Optional file, served next to the app, whose system configs override or extend the bundled ones
+const CONFIG_OVERRIDES_URL: &str = "system-configs.json";
+
+/// Id of the `<link>` carrying the theme stylesheet
+const THEME_LINK_ID: &str = "theme-stylesheet";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Breadcrumb {
+    pub kind: SystemKind,
     pub system_name: String,
 }
 
+/// What the main view is showing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MainView {
+    System,
+    Gallery,
+    Comparison,
+    Glossary,
+}
+
 pub enum ApiAppMsg {
-    SelectSystem(String),
+    SelectSystem(SystemKind),
     SystemsLoaded(Vec<SystemView>),
-    SystemLoaded(SystemView),
+    SystemLoaded(Box<SystemView>),
     LoadError(String),
-    NavigateToSystem(String),
+    NavigateToSystem(SystemKind),
     NavigateBack,
+    /// Open a system with a node or edge selected
+    OpenAt(SystemKind, Focus),
+    SetSearchOpen(bool),
+    Shortcut(Shortcut),
+    SelectTheme(ThemeKind),
+    SelectPalette(Palette),
+    SelectColourLanguage(Language),
+    SelectLayout(LayoutKind),
+    /// The user selected a node or edge in the open system, or cleared the selection
+    SelectionChanged(Option<Focus>),
     ToggleEdgeLabels,
+    ShowView(MainView),
+    SelectSlicePosition(i32),
+    SliceLoaded(i32, Vec<Slice>),
+    /// The backend has no slice query; cut the slice from cached systems
+    SliceQueryUnsupported(i32),
+    SliceLoadError(i32, String),
+    ConfigOverridesLoaded(Vec<SystemConfig>),
+    ConfigOverridesFailed(String),
 }
 
 pub struct ApiApp {
     systems: Vec<SystemView>,
     selected_system: Option<SystemView>,
+    /// `systems` and `selected_system` with their colours resolved for display,
+    /// kept in step by `repaint`
+    painted_systems: Vec<SystemView>,
+    painted_system: Option<SystemView>,
     loading: bool,
     error: Option<String>,
     graphql_client: GraphQLClient,
     breadcrumbs: Vec<Breadcrumb>,
     show_edge_labels: bool,
+    view: MainView,
+    /// Node or edge to select in the open system
+    focus: Option<Focus>,
+    search_open: bool,
+    help_open: bool,
+    theme: ThemeKind,
+    palette: Palette,
+    /// Language to take node colours from where the API gives several
+    colour_language: Language,
+    layout: LayoutKind,
+    /// Node or edge selected in the open system, kept for the session
+    selection: Option<Focus>,
+    /// Session from the last visit, restored once the systems have loaded
+    restore: Option<Session>,
+    _keydown: Option<EventListener>,
+    slice_position: Option<i32>,
+    slice: Option<(Vec<Slice>, SliceSource)>,
+    slice_error: Option<String>,
+    /// Bundled system configs, with any overrides applied
+    configs: Vec<SystemConfig>,
+    /// Why the config overrides were rejected, if they were
+    config_error: Option<String>,
 }
 
 impl Component for ApiApp {
@@ -55,94 +132,179 @@ impl Component for ApiApp {
             }
         });
 
+        // Teams can drop a config file next to the app to restyle or add systems
+        let link = ctx.link().clone();
+        spawn_local(async move {
+            let Ok(response) = Request::get(CONFIG_OVERRIDES_URL).send().await else { return };
+            if !response.ok() {
+                return;
+            }
+            let loaded = match response.text().await {
+                Ok(json) => SystemConfig::parse(CONFIG_OVERRIDES_URL, &json).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            link.send_message(match loaded {
+                Ok(configs) => ApiAppMsg::ConfigOverridesLoaded(configs),
+                Err(e) => ApiAppMsg::ConfigOverridesFailed(e),
+            });
+        });
+
+        let preferences = Preferences::load();
+        let theme = preferences.theme();
+        apply_theme_stylesheet(theme);
+
+        // Keyboard shortcuts work anywhere on the page except inside form fields
+        let link = ctx.link().clone();
+        let keydown = web_sys::window().map(|window| {
+            EventListener::new(&window, "keydown", move |event| {
+                let Some(e) = event.dyn_ref::<web_sys::KeyboardEvent>() else { return };
+                if let Some(shortcut) = shortcut_for(e) {
+                    e.prevent_default();
+                    link.send_message(ApiAppMsg::Shortcut(shortcut));
+                }
+            })
+        });
+
         Self {
             systems: vec![],
             selected_system: None,
+            painted_systems: vec![],
+            painted_system: None,
             loading: true,
             error: None,
             graphql_client,
             breadcrumbs: vec![],
-            show_edge_labels: false,
+            show_edge_labels: preferences.show_edge_labels,
+            view: MainView::System,
+            focus: None,
+            search_open: false,
+            help_open: false,
+            theme,
+            palette: preferences.palette(),
+            colour_language: preferences.colour_language(),
+            layout: preferences.layout(),
+            selection: None,
+            restore: Session::load(),
+            _keydown: keydown,
+            slice_position: None,
+            slice: None,
+            slice_error: None,
+            configs: SystemConfig::get_all_systems(),
+            config_error: None,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            ApiAppMsg::SelectSystem(name) => {
-                // Clear breadcrumbs when manually selecting from sidebar
-                self.breadcrumbs.clear();
-                self.loading = true;
-                self.error = None;
-
-                // Fetch the selected system
-                let link = ctx.link().clone();
-                let client = self.graphql_client.clone();
-
-                spawn_local(async move {
-                    match client.fetch_system(&name).await {
-                        Ok(system) => {
-                            link.send_message(ApiAppMsg::SystemLoaded(system));
-                        }
-                        Err(e) => {
-                            link.send_message(ApiAppMsg::LoadError(e.to_string()));
-                        }
-                    }
-                });
-
+            ApiAppMsg::SelectSystem(kind) => {
+                self.select_system(ctx, kind);
                 true
             }
-            ApiAppMsg::NavigateToSystem(name) => {
-                // Add current system to breadcrumbs before navigating
-                if let Some(ref current) = self.selected_system {
-                    self.breadcrumbs.push(Breadcrumb {
-                        system_name: current.name.clone().unwrap_or_else(|| current.display_name()),
-                    });
+            ApiAppMsg::NavigateToSystem(kind) => {
+                self.navigate_to_system(ctx, kind);
+                true
+            }
+            ApiAppMsg::NavigateBack => {
+                self.navigate_back(ctx);
+                true
+            }
+            ApiAppMsg::OpenAt(kind, focus) => {
+                let already_open = self.selected_system.as_ref().and_then(|s| s.kind()) == Some(kind);
+                if already_open {
+                    self.view = MainView::System;
+                } else {
+                    self.navigate_to_system(ctx, kind);
                 }
-
-                self.loading = true;
-                self.error = None;
-
-                // Fetch the target system
-                let link = ctx.link().clone();
-                let client = self.graphql_client.clone();
-
-                spawn_local(async move {
-                    match client.fetch_system(&name).await {
-                        Ok(system) => {
-                            link.send_message(ApiAppMsg::SystemLoaded(system));
-                        }
-                        Err(e) => {
-                            link.send_message(ApiAppMsg::LoadError(e.to_string()));
-                        }
+                self.focus = Some(focus);
+                true
+            }
+            ApiAppMsg::SetSearchOpen(open) => {
+                self.search_open = open;
+                true
+            }
+            ApiAppMsg::Shortcut(shortcut) => match shortcut {
+                Shortcut::SelectSystem(kind) => {
+                    if self.systems.iter().any(|s| s.kind() == Some(kind)) {
+                        self.select_system(ctx, kind);
+                        true
+                    } else {
+                        false
                     }
-                });
-
+                }
+                Shortcut::ToggleEdgeLabels => {
+                    self.toggle_edge_labels();
+                    true
+                }
+                Shortcut::NavigateBack => {
+                    self.navigate_back(ctx);
+                    true
+                }
+                Shortcut::Search => {
+                    self.help_open = false;
+                    self.search_open = true;
+                    true
+                }
+                Shortcut::ToggleHelp => {
+                    self.help_open = !self.help_open;
+                    true
+                }
+                Shortcut::Close => {
+                    let was_open = self.search_open || self.help_open;
+                    self.search_open = false;
+                    self.help_open = false;
+                    was_open
+                }
+            },
+            ApiAppMsg::SelectTheme(theme) => {
+                self.theme = theme;
+                apply_theme_stylesheet(theme);
+                self.repaint();
+                self.save_preferences();
                 true
             }
-            ApiAppMsg::NavigateBack => {
-                if let Some(breadcrumb) = self.breadcrumbs.pop() {
-                    self.loading = true;
-                    self.error = None;
-
-                    // Fetch the previous system
-                    let link = ctx.link().clone();
-                    let client = self.graphql_client.clone();
-                    let name = breadcrumb.system_name;
-
-                    spawn_local(async move {
-                        match client.fetch_system(&name).await {
-                            Ok(system) => {
-                                link.send_message(ApiAppMsg::SystemLoaded(system));
-                            }
-                            Err(e) => {
-                                link.send_message(ApiAppMsg::LoadError(e.to_string()));
-                            }
-                        }
-                    });
+            ApiAppMsg::SelectPalette(palette) => {
+                self.palette = palette;
+                self.repaint();
+                self.save_preferences();
+                true
+            }
+            ApiAppMsg::ConfigOverridesLoaded(overrides) => {
+                match SystemConfig::merged(SystemConfig::get_all_systems(), overrides) {
+                    Ok(configs) => {
+                        self.configs = configs;
+                        self.config_error = None;
+                        self.repaint();
+                    }
+                    Err(errors) => {
+                        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                        self.config_error = Some(messages.join("; "));
+                    }
                 }
-
                 true
             }
+            ApiAppMsg::ConfigOverridesFailed(error) => {
+                self.config_error = Some(error);
+                true
+            }
+            ApiAppMsg::SelectColourLanguage(language) => {
+                self.colour_language = language;
+                self.repaint();
+                if let (Some(position), Some((_, SliceSource::Cache))) = (self.slice_position, &self.slice) {
+                    self.slice_from_cache(position);
+                }
+                self.save_preferences();
+                true
+            }
+            ApiAppMsg::SelectLayout(layout) => {
+                self.layout = layout;
+                self.save_preferences();
+                false
+            }
+            ApiAppMsg::SelectionChanged(selection) => {
+                self.selection = selection;
+                self.save_session();
+                false
+            }
             ApiAppMsg::SystemsLoaded(systems) => {
                 self.loading = false;
 
@@ -151,17 +313,31 @@ impl Component for ApiApp {
                     web_sys::console::log_1(&format!("  - order {} ({})", sys.order, sys.display_name()).into());
                 }
 
-                // Select the first system by default
-                if let Some(first_system) = systems.first() {
+                // Pick up where the last visit left off, or select the first system
+                let restored = self.restore.take().and_then(|session| {
+                    let kind = session.system?;
+                    let system = systems.iter().find(|s| s.kind() == Some(kind))?;
+                    Some((system.clone(), session))
+                });
+                if let Some((system, session)) = restored {
+                    self.selected_system = Some(system);
+                    self.breadcrumbs = session.breadcrumbs;
+                    self.focus = session.selection;
+                    self.selection = session.selection;
+                } else if let Some(first_system) = systems.first() {
                     self.selected_system = Some(first_system.clone());
                 }
 
                 self.systems = systems;
+                self.repaint();
                 true
             }
             ApiAppMsg::SystemLoaded(system) => {
                 self.loading = false;
-                self.selected_system = Some(system);
+                self.painted_system = Some(self.painted(&system));
+                self.selected_system = Some(*system);
+                self.selection = self.focus;
+                self.save_session();
                 true
             }
             ApiAppMsg::LoadError(error) => {
@@ -170,7 +346,60 @@ impl Component for ApiApp {
                 true
             }
             ApiAppMsg::ToggleEdgeLabels => {
-                self.show_edge_labels = !self.show_edge_labels;
+                self.toggle_edge_labels();
+                true
+            }
+            ApiAppMsg::ShowView(view) => {
+                self.view = view;
+                self.breadcrumbs.clear();
+                self.save_session();
+                true
+            }
+            ApiAppMsg::SelectSlicePosition(position) => {
+                self.slice_position = Some(position);
+                self.slice = None;
+                self.slice_error = None;
+
+                let link = ctx.link().clone();
+                let client = self.graphql_client.clone();
+
+                spawn_local(async move {
+                    match client.fetch_slice(position).await {
+                        Ok(slices) => {
+                            link.send_message(ApiAppMsg::SliceLoaded(position, slices));
+                        }
+                        Err(ApiError::Unsupported(_)) => {
+                            link.send_message(ApiAppMsg::SliceQueryUnsupported(position));
+                        }
+                        Err(e) => {
+                            link.send_message(ApiAppMsg::SliceLoadError(position, e.to_string()));
+                        }
+                    }
+                });
+
+                true
+            }
+            ApiAppMsg::SliceLoaded(position, slices) => {
+                // Ignore answers for a position the user has since moved away from
+                if self.slice_position != Some(position) {
+                    return false;
+                }
+                let slices = self.with_cached_coordinates(slices);
+                self.slice = Some((slice_entries(slices, position), SliceSource::Query));
+                true
+            }
+            ApiAppMsg::SliceLoadError(position, error) => {
+                if self.slice_position != Some(position) {
+                    return false;
+                }
+                self.slice_error = Some(error);
+                true
+            }
+            ApiAppMsg::SliceQueryUnsupported(position) => {
+                if self.slice_position != Some(position) {
+                    return false;
+                }
+                self.slice_from_cache(position);
                 true
             }
         }
@@ -181,6 +410,30 @@ impl Component for ApiApp {
         let on_navigate = ctx.link().callback(ApiAppMsg::NavigateToSystem);
         let on_back = ctx.link().callback(|_| ApiAppMsg::NavigateBack);
         let on_toggle_edge_labels = ctx.link().callback(|_| ApiAppMsg::ToggleEdgeLabels);
+        let selected_kind = self.selected_system
+            .as_ref()
+            .and_then(|s| s.kind())
+            .unwrap_or(SystemKind::MONAD);
+        let on_show_gallery = ctx.link().callback(|_| ApiAppMsg::ShowView(MainView::Gallery));
+        let on_show_comparison = ctx.link().callback(|_| ApiAppMsg::ShowView(MainView::Comparison));
+        let on_show_glossary = ctx.link().callback(|_| ApiAppMsg::ShowView(MainView::Glossary));
+        let on_open_at = ctx.link().callback(|(kind, focus)| ApiAppMsg::OpenAt(kind, focus));
+        let on_open_search = ctx.link().callback(|_| ApiAppMsg::SetSearchOpen(true));
+        let on_close_search = ctx.link().callback(|_| ApiAppMsg::SetSearchOpen(false));
+        let on_choose_result = ctx.link().batch_callback(|(kind, focus): (SystemKind, Option<Focus>)| {
+            let open = match focus {
+                Some(focus) => ApiAppMsg::OpenAt(kind, focus),
+                None => ApiAppMsg::SelectSystem(kind),
+            };
+            vec![ApiAppMsg::SetSearchOpen(false), open]
+        });
+        let on_select_slice_position = ctx.link().callback(ApiAppMsg::SelectSlicePosition);
+        let graph_theme = self.palette.adjust(self.theme.graph());
+        let colour_languages: Vec<Language> = self.systems.iter()
+            .flat_map(|system| system.colour_languages())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
 
         html! {
             <div class="app">
@@ -191,34 +444,38 @@ impl Component for ApiApp {
                                 html! { <div class="loading">{"Loading systems..."}</div> }
                             } else {
                                 // Convert SystemView to SystemConfig for SystemSelector
-                                let legacy_systems: Vec<SystemConfig> = self.systems.iter().map(|sys| {
-                                    SystemConfig {
-                                        name: sys.name.clone().unwrap_or_else(|| sys.display_name().to_lowercase()),
+                                let legacy_systems: Vec<SystemConfig> = self.systems.iter().filter_map(|sys| {
+                                    Some(SystemConfig {
+                                        kind: sys.kind()?,
                                         display_name: sys.display_name(),
                                         node_count: sys.node_count(),
                                         k_notation: sys.k_notation(),
                                         description: sys.description(),
-                                        color_scheme: crate::core::system_config::ColorScheme {
-                                            nodes: "#4A90E2".to_string(),
-                                            edges: "#888888".to_string(),
-                                            selected_node: "#FF6B6B".to_string(),
-                                            selected_edge: "#FF6B6B".to_string(),
-                                        },
-                                    }
+                                        color_scheme: self.color_scheme_for(sys.kind()),
+                                    })
                                 }).collect();
 
-                                let selected_name = self.selected_system
-                                    .as_ref()
-                                    .map(|s| s.name.clone().unwrap_or_else(|| s.display_name().to_lowercase()))
-                                    .unwrap_or_else(|| "monad".to_string());
-
                                 html! {
                                     <SystemSelector
                                         systems={ legacy_systems }
-                                        selected={ selected_name }
-                                        on_select={ on_select }
+                                        selected={ selected_kind }
+                                        on_select={ on_select.clone() }
                                         show_edge_labels={ self.show_edge_labels }
                                         on_toggle_edge_labels={ Some(on_toggle_edge_labels.clone()) }
+                                        show_gallery={ self.view == MainView::Gallery }
+                                        on_show_gallery={ Some(on_show_gallery) }
+                                        show_comparison={ self.view == MainView::Comparison }
+                                        on_show_comparison={ Some(on_show_comparison) }
+                                        show_glossary={ self.view == MainView::Glossary }
+                                        on_show_glossary={ Some(on_show_glossary) }
+                                        on_search={ Some(on_open_search) }
+                                        theme={ self.theme }
+                                        on_select_theme={ Some(ctx.link().callback(ApiAppMsg::SelectTheme)) }
+                                        palette={ self.palette }
+                                        on_select_palette={ Some(ctx.link().callback(ApiAppMsg::SelectPalette)) }
+                                        colour_languages={ colour_languages }
+                                        colour_language={ self.colour_language }
+                                        on_select_colour_language={ Some(ctx.link().callback(ApiAppMsg::SelectColourLanguage)) }
                                     />
                                 }
                             }
@@ -226,6 +483,14 @@ impl Component for ApiApp {
                     </aside>
 
                     <main class="main-view">
+                        <div class="sr-only" aria-live="polite" aria-atomic="true">
+                            { self.status_announcement() }
+                        </div>
+                        if let Some(ref error) = self.config_error {
+                            <div class="config-warning" role="status">
+                                { format!("Ignoring {}: {}", CONFIG_OVERRIDES_URL, error) }
+                            </div>
+                        }
                         // Breadcrumb trail
                         if !self.breadcrumbs.is_empty() {
                             <nav class="breadcrumbs">
@@ -256,23 +521,248 @@ impl Component for ApiApp {
                                         <p>{ error }</p>
                                     </div>
                                 }
-                            } else if self.loading {
-                                html! { <div class="loading">{"Loading system..."}</div> }
-                            } else if let Some(ref system) = self.selected_system {
+                            } else if self.view == MainView::Gallery {
+                                html! {
+                                    <SystemGallery
+                                        systems={ self.painted_systems.clone() }
+                                        theme={ graph_theme }
+                                        on_open={ on_select.clone() }
+                                    />
+                                }
+                            } else if self.view == MainView::Glossary {
+                                html! {
+                                    <GlossaryView
+                                        systems={ self.systems.clone() }
+                                        on_open={ on_open_at.clone() }
+                                    />
+                                }
+                            } else if self.view == MainView::Comparison {
                                 html! {
-                                    <ApiGraphView
-                                        system={ system.clone() }
-                                        on_navigate={ Some(on_navigate) }
+                                    <SystemComparison
+                                        systems={ self.painted_systems.clone() }
+                                        initial={ selected_kind }
                                         show_edge_labels={ self.show_edge_labels }
+                                        theme={ graph_theme }
+                                        keyboard_navigation={ !self.help_open && !self.search_open }
                                     />
                                 }
+                            } else if self.loading {
+                                html! { <div class="loading">{"Loading system..."}</div> }
+                            } else if let Some(ref system) = self.painted_system {
+                                html! {
+                                    <>
+                                        <SystemHeader system={ system.clone() } />
+                                        <ApiGraphView
+                                            system={ system.clone() }
+                                            on_navigate={ Some(on_navigate.clone()) }
+                                            show_edge_labels={ self.show_edge_labels }
+                                            focus={ self.focus }
+                                            keyboard_navigation={ !self.help_open && !self.search_open }
+                                            theme={ graph_theme }
+                                            color_scheme={ (self.palette.kind == PaletteKind::Api).then(|| self.color_scheme_for(system.kind())) }
+                                            layout={ self.layout }
+                                            on_layout_change={ Some(ctx.link().callback(ApiAppMsg::SelectLayout)) }
+                                            on_selection_change={ Some(ctx.link().callback(ApiAppMsg::SelectionChanged)) }
+                                        />
+                                        <SliceBrowser
+                                            position={ self.slice_position }
+                                            slice={ self.slice.clone() }
+                                            error={ self.slice_error.clone() }
+                                            on_select_position={ on_select_slice_position }
+                                            on_navigate={ on_navigate }
+                                        />
+                                    </>
+                                }
                             } else {
                                 html! { <div class="loading">{"Select a system"}</div> }
                             }
                         }
                     </main>
                 </div>
+                if self.help_open {
+                    <ShortcutHelp on_close={ ctx.link().callback(|_| ApiAppMsg::Shortcut(Shortcut::Close)) } />
+                }
+                if self.search_open {
+                    <SearchPalette
+                        systems={ self.systems.clone() }
+                        on_choose={ on_choose_result }
+                        on_close={ on_close_search }
+                    />
+                }
             </div>
         }
     }
 }
+
+impl ApiApp {
+    /// Open a system from the navigation, starting a fresh breadcrumb trail
+    fn select_system(&mut self, ctx: &Context<Self>, kind: SystemKind) {
+        self.breadcrumbs.clear();
+        self.view = MainView::System;
+        self.focus = None;
+        self.load_system(ctx, kind);
+    }
+
+    /// Open a system reached from the current one, remembering the way back
+    fn navigate_to_system(&mut self, ctx: &Context<Self>, kind: SystemKind) {
+        self.view = MainView::System;
+        self.focus = None;
+
+        if let Some(ref current) = self.selected_system {
+            if let Some(current_kind) = current.kind() {
+                self.breadcrumbs.push(Breadcrumb {
+                    kind: current_kind,
+                    system_name: current.display_name(),
+                });
+            }
+        }
+        self.load_system(ctx, kind);
+    }
+
+    /// Return to the system at the end of the breadcrumb trail
+    fn navigate_back(&mut self, ctx: &Context<Self>) {
+        if let Some(breadcrumb) = self.breadcrumbs.pop() {
+            self.focus = None;
+            self.load_system(ctx, breadcrumb.kind);
+        }
+    }
+
+    fn toggle_edge_labels(&mut self) {
+        self.show_edge_labels = !self.show_edge_labels;
+        self.save_preferences();
+    }
+
+    /// Fetch a system, answering with `SystemLoaded` or `LoadError`
+    fn load_system(&mut self, ctx: &Context<Self>, kind: SystemKind) {
+        self.loading = true;
+        self.error = None;
+
+        let link = ctx.link().clone();
+        let client = self.graphql_client.clone();
+
+        spawn_local(async move {
+            match client.fetch_system_by_order(kind.order() as i32).await {
+                Ok(system) => {
+                    link.send_message(ApiAppMsg::SystemLoaded(Box::new(system)));
+                }
+                Err(e) => {
+                    link.send_message(ApiAppMsg::LoadError(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Queried slices carry raw API coordinates; swap in the cached systems' viewport
+    /// coordinates so both slice sources show the same space
+    /// Cut the slice at `position` from the cached systems, in the chosen colour language
+    fn slice_from_cache(&mut self, position: i32) {
+        if self.systems.is_empty() {
+            self.slice_error = Some("The slice query is unavailable and no systems are cached yet".to_string());
+        } else {
+            let slices = self.systems.iter()
+                .filter_map(|system| Slice::from_system(system, position, self.colour_language))
+                .collect();
+            self.slice = Some((slice_entries(slices, position), SliceSource::Cache));
+        }
+    }
+
+    fn with_cached_coordinates(&self, mut slices: Vec<Slice>) -> Vec<Slice> {
+        for slice in slices.iter_mut() {
+            slice.coordinate = self.systems.iter()
+                .find(|system| system.order == slice.order)
+                .and_then(|system| system.coordinate_at(slice.position))
+                .cloned();
+        }
+        slices
+    }
+
+    fn save_preferences(&self) {
+        Preferences {
+            show_edge_labels: self.show_edge_labels,
+            theme: self.theme.key().to_string(),
+            colour_language: self.colour_language.key().to_string(),
+            layout: self.layout.key().to_string(),
+            palette: self.palette.kind.key().to_string(),
+            palette_mode: self.palette.mode.key().to_string(),
+            ..Preferences::default()
+        }.save();
+    }
+
+    fn save_session(&self) {
+        Session {
+            system: self.selected_system.as_ref().and_then(|s| s.kind()),
+            breadcrumbs: self.breadcrumbs.clone(),
+            selection: self.selection,
+            ..Session::default()
+        }.save();
+    }
+
+    /// The configured colours for a system, or the defaults if it has no config
+    fn color_scheme_for(&self, kind: Option<SystemKind>) -> ColorScheme {
+        kind.and_then(|kind| self.configs.iter().find(|config| config.kind == kind))
+            .map(|config| config.color_scheme.clone())
+            .unwrap_or_default()
+    }
+
+    /// The system with colours resolved for display: the chosen colour language, then the
+    /// palette. The configured node colour is picked to sit on the default theme, so it
+    /// fills uncoloured positions only there; other themes keep their own node colour.
+    fn painted(&self, system: &SystemView) -> SystemView {
+        let painted = system.with_colour_language(self.colour_language)
+            .with_palette(&self.palette);
+        if self.theme == ThemeKind::default() {
+            painted.with_default_colour(&self.color_scheme_for(system.kind()).nodes)
+        } else {
+            painted
+        }
+    }
+
+    /// Resolve colours again after the systems or any colour setting changed
+    fn repaint(&mut self) {
+        self.painted_systems = self.systems.iter().map(|system| self.painted(system)).collect();
+        self.painted_system = self.selected_system.as_ref().map(|system| self.painted(system));
+    }
+
+    /// Text for the live region, so screen readers hear when a system loads or fails
+    fn status_announcement(&self) -> String {
+        if let Some(ref error) = self.error {
+            format!("Error: {}", error)
+        } else if self.loading {
+            "Loading system".to_string()
+        } else if let (MainView::System, Some(system)) = (self.view, self.selected_system.as_ref()) {
+            format!("{} ({}) loaded, {} terms", system.display_name(), system.k_notation(), system.terms.len())
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Point the theme `<link>` at the theme's stylesheet, creating it on first use.
+/// The default theme is `style.css` alone, so the link is left without an href.
+fn apply_theme_stylesheet(theme: ThemeKind) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+
+    let link = match document.get_element_by_id(THEME_LINK_ID) {
+        Some(link) => link,
+        None => {
+            let (Ok(link), Some(head)) = (document.create_element("link"), document.head()) else { return };
+            let _ = link.set_attribute("id", THEME_LINK_ID);
+            let _ = link.set_attribute("rel", "stylesheet");
+            let _ = head.append_child(&link);
+            link
+        }
+    };
+
+    match theme.stylesheet() {
+        Some(href) => { let _ = link.set_attribute("href", href); }
+        None => { let _ = link.remove_attribute("href"); }
+    }
+}
+
+/// Keep the orders from `position` up to the Dodecad, lowest first
+fn slice_entries(mut slices: Vec<Slice>, position: i32) -> Vec<Slice> {
+    let max_order = SystemKind::DODECAD.order() as i32;
+    slices.retain(|slice| slice.order >= position && slice.order <= max_order);
+    slices.sort_by_key(|slice| slice.order);
+    slices
+}
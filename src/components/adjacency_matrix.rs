@@ -0,0 +1,179 @@
+use std::rc::Rc;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::api::models::SystemView;
+
+/// Order of the matrix's rows and columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatrixOrder {
+    Position,
+    Term,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AdjacencyMatrixProps {
+    pub system: Rc<SystemView>,
+    /// Selected node, as a 0-based index
+    #[prop_or_default]
+    pub selected_node: Option<usize>,
+    /// Selected edge, as 0-based indices with the lower first
+    #[prop_or_default]
+    pub selected_edge: Option<(usize, usize)>,
+    pub on_select_node: Callback<usize>,
+    pub on_select_edge: Callback<(usize, usize)>,
+}
+
+/// The system as an n×n matrix: rows are base terms, columns are target terms and
+/// cells hold the connective between them
+#[function_component(AdjacencyMatrix)]
+pub fn adjacency_matrix(props: &AdjacencyMatrixProps) -> Html {
+    let system = &props.system;
+    let order = use_state(|| MatrixOrder::Position);
+    let positions = ordered_positions(system, *order);
+
+    let on_order_change = {
+        let order = order.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            order.set(if select.value() == "term" { MatrixOrder::Term } else { MatrixOrder::Position });
+        })
+    };
+
+    let csv_href = format!("data:text/csv;charset=utf-8,{}", percent_encode(&matrix_csv(system, &positions)));
+    let csv_name = format!("{}-matrix.csv", system.display_name().to_lowercase());
+
+    let header_cell = |position: i32, scope: &'static str| {
+        let idx = (position - 1) as usize;
+        let on_select_node = props.on_select_node.clone();
+        let class = classes!("matrix-term", (props.selected_node == Some(idx)).then_some("selected"));
+        let style = system.colour_at(position).map(|colour| format!("--term-colour: {}", colour));
+
+        html! {
+            <th scope={ scope } class={ class } style={ style } onclick={ Callback::from(move |_| on_select_node.emit(idx)) }>
+                { system.term_at(position).unwrap_or("") }
+                <span class="matrix-position">{ position }</span>
+            </th>
+        }
+    };
+
+    let cell = |base: i32, target: i32| {
+        if base == target {
+            return html! { <td class="matrix-cell diagonal"></td> };
+        }
+
+        let (a, b) = ((base - 1) as usize, (target - 1) as usize);
+        let edge = (a.min(b), a.max(b));
+        let forward = system.connective_label(base, target);
+        // Connectives given only the other way round still describe this pair
+        let label = forward.or_else(|| system.connective_label(target, base)).unwrap_or("");
+
+        let selected = props.selected_edge == Some(edge)
+            || props.selected_node.is_some_and(|node| node == a || node == b);
+        let class = classes!(
+            "matrix-cell",
+            (forward.is_none() && !label.is_empty()).then_some("reverse"),
+            selected.then_some("selected"),
+        );
+        let style = format!(
+            "--row-colour: {}; --col-colour: {}",
+            system.colour_at(base).unwrap_or("transparent"),
+            system.colour_at(target).unwrap_or("transparent"),
+        );
+        let on_select_edge = props.on_select_edge.clone();
+
+        html! {
+            <td class={ class } style={ style } onclick={ Callback::from(move |_| on_select_edge.emit(edge)) }>
+                { label }
+            </td>
+        }
+    };
+
+    html! {
+        <section class="legend adjacency-matrix">
+            <h3 class="legend-title">{ format!("{} matrix", system.connective_legend_title()) }</h3>
+            <div class="matrix-controls">
+                <label class="layout-select">
+                    {"Sort by"}
+                    <select onchange={ on_order_change }>
+                        <option value="position" selected={ *order == MatrixOrder::Position }>{"Position"}</option>
+                        <option value="term" selected={ *order == MatrixOrder::Term }>{"Term"}</option>
+                    </select>
+                </label>
+                <a class="matrix-export" href={ csv_href } download={ csv_name }>{"Export CSV"}</a>
+            </div>
+            <div class="matrix-scroll">
+                <table>
+                    <thead>
+                        <tr>
+                            <th class="matrix-corner">{"base \\ target"}</th>
+                            { for positions.iter().map(|&p| header_cell(p, "col")) }
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for positions.iter().map(|&base| html! {
+                            <tr>
+                                { header_cell(base, "row") }
+                                { for positions.iter().map(|&target| cell(base, target)) }
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+            </div>
+        </section>
+    }
+}
+
+/// Positions (1-based) in display order; terms sort case-insensitively, unnamed last
+fn ordered_positions(system: &SystemView, order: MatrixOrder) -> Vec<i32> {
+    let mut positions: Vec<i32> = (1..=system.order).collect();
+    if order == MatrixOrder::Term {
+        positions.sort_by_key(|&p| (system.term_at(p).is_none(), system.term_at(p).map(str::to_lowercase), p));
+    }
+    positions
+}
+
+/// The matrix as CSV, with a header row and column of terms
+fn matrix_csv(system: &SystemView, positions: &[i32]) -> String {
+    let term = |p: i32| csv_field(system.term_at(p).unwrap_or(&p.to_string()));
+
+    let mut csv = String::new();
+    for &target in positions {
+        csv.push(',');
+        csv.push_str(&term(target));
+    }
+    csv.push('\n');
+
+    for &base in positions {
+        csv.push_str(&term(base));
+        for &target in positions {
+            csv.push(',');
+            if base != target {
+                let label = system.connective_label(base, target)
+                    .or_else(|| system.connective_label(target, base))
+                    .unwrap_or("");
+                csv.push_str(&csv_field(label));
+            }
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Percent-encode everything but unreserved characters, for use in a data URI
+fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
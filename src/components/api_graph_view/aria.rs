@@ -0,0 +1,116 @@
+use std::sync::atomic::AtomicUsize;
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use super::connectives::ConnectiveIndex;
+use super::ApiGraphView;
+
+impl ApiGraphView {
+    pub(super) fn description_id(&self) -> String {
+        format!("graph-description-{}", self.instance)
+    }
+
+    /// Visually hidden text version of the system: its terms and every connective
+    pub(super) fn render_description(&self, system: &SystemView) -> Html {
+        html! {
+            <div id={ self.description_id() } class="sr-only">
+                <p>
+                    { format!(
+                        "{} ({}), {} terms and {} connectives.",
+                        system.display_name(), system.k_notation(),
+                        system.terms.len(), system.connectives.len(),
+                    ) }
+                    if let Some(ref coherence) = system.coherence {
+                        { format!(" {}.", coherence) }
+                    }
+                </p>
+                <table>
+                    <caption>{ system.term_legend_title() }</caption>
+                    <thead>
+                        <tr><th scope="col">{"Position"}</th><th scope="col">{"Term"}</th></tr>
+                    </thead>
+                    <tbody>
+                        { for (1..=system.order).map(|position| html! {
+                            <tr>
+                                <th scope="row">{ position }</th>
+                                <td>{ system.term_at(position).unwrap_or("") }</td>
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+                <ul aria-label={ system.connective_legend_title() }>
+                    { for system.connectives.iter().filter_map(|conn| {
+                        let (base, target) = (conn.base_position?, conn.target_position?);
+                        let label = conn.character.as_ref().map(|c| c.value.as_str()).unwrap_or("");
+                        Some(html! {
+                            <li>{ format!("{} → {}: {}", term_name(system, base), term_name(system, target), label) }</li>
+                        })
+                    })}
+                </ul>
+            </div>
+        }
+    }
+
+    /// Text for the live region, describing what is selected
+    pub(super) fn selection_announcement(&self, system: &SystemView, connectives: &ConnectiveIndex) -> String {
+        if let Some(idx) = self.selected_node {
+            format!("Selected {}", node_aria_label(system, connectives, idx as i32 + 1))
+        } else if let Some((a, b)) = self.selected_edge {
+            format!("Selected {}", edge_aria_label(system, connectives, a as i32 + 1, b as i32 + 1))
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Source of ids for each view's described-by target, so several views can share a page
+pub(super) static NEXT_INSTANCE: AtomicUsize = AtomicUsize::new(0);
+
+/// "Term 2: Energy", or just "Term 2" when the term has no character
+pub(super) fn term_name(system: &SystemView, position: i32) -> String {
+    match system.term_at(position) {
+        Some(term) => format!("Term {}: {}", position, term),
+        None => format!("Term {}", position),
+    }
+}
+
+/// Screen-reader label for a node: its term and what it connects to, through which connective
+pub(super) fn node_aria_label(system: &SystemView, connectives: &ConnectiveIndex, position: i32) -> String {
+    let neighbours: Vec<String> = system.lines.iter()
+        .filter_map(|line| {
+            let (base, target) = (line.base_position?, line.target_position?);
+            let other = if base == position { target } else if target == position { base } else { return None };
+            let term = system.term_at(other).map(str::to_string).unwrap_or_else(|| format!("term {}", other));
+            Some(match connective_between(connectives, position, other) {
+                Some(connective) => format!("{} via {}", term, connective),
+                None => term,
+            })
+        })
+        .collect();
+
+    if neighbours.is_empty() {
+        term_name(system, position)
+    } else {
+        format!("{}, connected to {}", term_name(system, position), neighbours.join(", "))
+    }
+}
+
+/// Screen-reader label for the edge between two positions
+pub(super) fn edge_aria_label(system: &SystemView, connectives: &ConnectiveIndex, base: i32, target: i32) -> String {
+    match connective_between(connectives, base, target) {
+        Some(connective) => format!(
+            "{} between {} and {}",
+            connective, term_name(system, base), term_name(system, target),
+        ),
+        None => format!("Line between {} and {}", term_name(system, base), term_name(system, target)),
+    }
+}
+
+/// Connective label between two positions, in whichever direction it is given
+fn connective_between<'a>(connectives: &ConnectiveIndex<'a>, a: i32, b: i32) -> Option<&'a str> {
+    let pair = connectives.get(&(a.min(b), a.max(b)))?;
+    let label = |base: i32, target: i32| pair.iter()
+        .find(|c| c.base_position == Some(base) && c.target_position == Some(target))
+        .and_then(|c| c.character.as_ref())
+        .map(|c| c.value.as_str());
+    label(a, b).or_else(|| label(b, a))
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use yew::prelude::*;
+use crate::api::models::{Link, SystemView};
+use crate::core::geometry::GeometryCalculator;
+use super::{ApiGraphMsg, ApiGraphView};
+
+/// Edges coloured by chord class, the step between their ends around the regular
+/// polygon, with any classes the user has hidden
+#[derive(Default)]
+pub(super) struct ChordClasses {
+    pub(super) colouring: bool,
+    /// Chord classes (by step length) whose edges are hidden
+    pub(super) hidden_steps: HashSet<usize>,
+}
+
+impl ChordClasses {
+    /// Turn colouring on or off; turning it off shows every class again
+    pub(super) fn toggle_colouring(&mut self) {
+        self.colouring = !self.colouring;
+        if !self.colouring {
+            self.hidden_steps.clear();
+        }
+    }
+
+    /// Hide the class with this step, or show it again
+    pub(super) fn toggle_step(&mut self, step: usize) {
+        if !self.hidden_steps.remove(&step) {
+            self.hidden_steps.insert(step);
+        }
+    }
+}
+
+impl ApiGraphView {
+    /// Render the chord colouring toggle, disabled off the regular polygon
+    pub(super) fn render_chord_toggle(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <label
+                class="overlay-toggle"
+                title={ (!self.layout.has_cyclic_order()).then_some("Chord classes follow the regular polygon's order") }
+            >
+                <input
+                    type="checkbox"
+                    checked={ self.chords.colouring }
+                    disabled={ !self.layout.has_cyclic_order() }
+                    onchange={ ctx.link().callback(|_| ApiGraphMsg::ToggleChordColouring) }
+                />
+                {"Chord classes"}
+            </label>
+        }
+    }
+
+    /// Render the chord-class legend, where each class can be toggled on and off
+    pub(super) fn render_chord_legend(&self, ctx: &Context<Self>, system: &SystemView) -> Html {
+        let node_count = system.node_count();
+        let classes = GeometryCalculator::chord_classes(node_count);
+
+        if classes.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <section class="legend chord-legend">
+                <h3 class="legend-title">{"Chord classes"}</h3>
+                <ul>
+                    { for classes.iter().map(|class| {
+                        let step = class.step;
+                        let onchange = ctx.link().callback(move |_| ApiGraphMsg::ToggleChordClass(step));
+                        html! {
+                            <li class="legend-item">
+                                <label class="overlay-toggle">
+                                    <input
+                                        type="checkbox"
+                                        checked={ !self.chords.hidden_steps.contains(&step) }
+                                        onchange={ onchange }
+                                    />
+                                    <span class="legend-swatch" style={ format!("background: {};", ctx.props().theme.edge_class(step - 1)) }></span>
+                                    <span class="legend-key">{ class.schlafli(node_count) }</span>
+                                    <span class="legend-value">{ format!("{} edges", class.edges.len()) }</span>
+                                </label>
+                            </li>
+                        }
+                    })}
+                </ul>
+            </section>
+        }
+    }
+
+    /// Whether a line's chord class is currently shown
+    pub(super) fn is_line_visible(&self, system: &SystemView, line: &Link) -> bool {
+        let (Some(base), Some(target)) = (line.base_position, line.target_position) else {
+            return true;
+        };
+        let step = GeometryCalculator::chord_step(system.node_count(), (base - 1) as usize, (target - 1) as usize);
+        !self.chords.hidden_steps.contains(&step)
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use crate::api::models::{Link, SystemView};
+use crate::core::geometry::Point;
+
+/// Distance of a paired connective's curve from the straight edge
+const PAIRED_EDGE_BEND: f64 = 18.0;
+
+/// Connectives keyed by their unordered position pair, so each line can find its
+/// connectives without scanning the whole list (K20+ has hundreds of each)
+pub(super) type ConnectiveIndex<'a> = HashMap<(i32, i32), Vec<&'a Link>>;
+
+pub(super) fn index_connectives(system: &SystemView) -> ConnectiveIndex<'_> {
+    let mut index: ConnectiveIndex = HashMap::new();
+    for conn in &system.connectives {
+        let base = conn.base_position.unwrap_or(0);
+        let target = conn.target_position.unwrap_or(0);
+        index.entry((base.min(target), base.max(target))).or_default().push(conn);
+    }
+    index
+}
+
+/// A connective resolved to viewport coordinates in its own direction
+pub(super) struct DirectedConnective<'a> {
+    pub(super) label: &'a str,
+    /// Positions (1-based) the connective runs between
+    pub(super) base: i32,
+    pub(super) target: i32,
+    pub(super) from: Point,
+    pub(super) to: Point,
+    /// Perpendicular bend of the curve's control point (0 for a straight edge)
+    pub(super) bend: f64,
+}
+
+impl DirectedConnective<'_> {
+    /// Unit normal to the left of the connective's direction
+    pub(super) fn normal(&self) -> (f64, f64) {
+        let dx = self.to.x - self.from.x;
+        let dy = self.to.y - self.from.y;
+        let length = (dx * dx + dy * dy).sqrt().max(f64::EPSILON);
+        (dy / length, -dx / length)
+    }
+
+    /// Control point of the quadratic curve (the midpoint for a straight edge)
+    pub(super) fn control_point(&self) -> Point {
+        let (nx, ny) = self.normal();
+        Point {
+            x: (self.from.x + self.to.x) / 2.0 + nx * self.bend,
+            y: (self.from.y + self.to.y) / 2.0 + ny * self.bend,
+        }
+    }
+}
+
+/// Resolve the connectives on a line, in either direction. When connectives run
+/// both ways between the same pair, each is bent to its own side so they form
+/// two parallel curves.
+pub(super) fn directed_connectives<'a>(
+    positions: &[Point],
+    index: &ConnectiveIndex<'a>,
+    line: &Link,
+) -> Vec<DirectedConnective<'a>> {
+    let base = line.base_position.unwrap_or(0);
+    let target = line.target_position.unwrap_or(0);
+
+    let connectives: Vec<&Link> = index
+        .get(&(base.min(target), base.max(target)))
+        .cloned()
+        .unwrap_or_default();
+    let forward = connectives.iter().any(|c| c.base_position == Some(base));
+    let backward = connectives.iter().any(|c| c.base_position == Some(target));
+    let bend = if forward && backward { PAIRED_EDGE_BEND * 2.0 } else { 0.0 };
+
+    connectives.into_iter().filter_map(|conn| {
+        let (base, target) = (conn.base_position?, conn.target_position?);
+        let from = positions.get(usize::try_from(base - 1).ok()?)?;
+        let to = positions.get(usize::try_from(target - 1).ok()?)?;
+        let label = conn.character.as_ref().map(|c| c.value.as_str()).unwrap_or("");
+
+        Some(DirectedConnective {
+            label,
+            base,
+            target,
+            from: from.clone(),
+            to: to.clone(),
+            bend,
+        })
+    }).collect()
+}
@@ -0,0 +1,182 @@
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::core::decomposition::Decomposition;
+use crate::core::geometry::{GeometryCalculator, Point};
+use super::aria::{edge_aria_label, term_name};
+use super::connectives::{directed_connectives, ConnectiveIndex};
+use super::factors::FactorView;
+use super::keyboard::on_activate_key;
+use super::{ApiGraphMsg, ApiGraphView};
+
+impl ApiGraphView {
+    /// Render edges (lines) from the system
+    /// When connectives are shown, edges carry arrowheads in the connective's
+    /// direction, and pairs with connectives both ways are drawn as two curves.
+    pub(super) fn render_edges(&self, ctx: &Context<Self>, system: &SystemView, connectives: &ConnectiveIndex, decomposition: Option<&Decomposition>, show_connectives: bool) -> Html {
+        let theme = &ctx.props().theme;
+        let selected_edge = ctx.props().color_scheme.as_ref()
+            .map_or(AttrValue::Static(theme.selected_edge), |scheme| scheme.selected_edge.clone().into());
+        let node_radius = GeometryCalculator::node_radius_for(system.node_count());
+
+        system.lines.iter().map(|line| {
+            // Get positions (1-based from API)
+            let base_pos = line.base_position.unwrap_or(0);
+            let target_pos = line.target_position.unwrap_or(0);
+
+            if base_pos <= 0 || target_pos <= 0 {
+                web_sys::console::log_1(&"Skipping line: invalid positions".into());
+                return html! {};
+            }
+
+            // Look up the nodes' current positions
+            // (Don't use embedded link coordinates - they aren't transformed correctly)
+            let (from_x, from_y) = if let Some(point) = self.node_point(base_pos) {
+                (point.x, point.y)
+            } else {
+                web_sys::console::log_1(&format!("Could not find from coordinate for pos {}", base_pos).into());
+                return html! {};
+            };
+
+            let (to_x, to_y) = if let Some(point) = self.node_point(target_pos) {
+                (point.x, point.y)
+            } else {
+                web_sys::console::log_1(&format!("Could not find to coordinate for pos {}", target_pos).into());
+                return html! {};
+            };
+
+            // Convert to 0-based for selection comparison
+            let from_idx = (base_pos - 1) as usize;
+            let to_idx = (target_pos - 1) as usize;
+
+            let edge_tuple = if from_idx < to_idx {
+                (from_idx, to_idx)
+            } else {
+                (to_idx, from_idx)
+            };
+
+            let step = GeometryCalculator::chord_step(system.node_count(), from_idx, to_idx);
+            if self.chords.hidden_steps.contains(&step) {
+                return html! {};
+            }
+
+            let factor = decomposition.and_then(|d| d.factor_of(from_idx, to_idx));
+            let outside_step = match self.factor_view {
+                FactorView::Step(current) => factor != Some(current),
+                _ => false,
+            };
+
+            let is_selected = self.selected_edge == Some(edge_tuple);
+            let stroke = if is_selected {
+                selected_edge.clone()
+            } else if let (FactorView::Step(_) | FactorView::All, Some(factor)) = (self.factor_view, factor) {
+                AttrValue::Static(theme.edge_class(factor))
+            } else if self.chords.colouring && step > 0 {
+                AttrValue::Static(theme.edge_class(step - 1))
+            } else {
+                AttrValue::Static(theme.edge)
+            };
+            let stroke_width = if is_selected { 3.0 } else { 1.5 };
+            let class = classes!(
+                "edge",
+                outside_step.then_some("dimmed"),
+                self.subset_class(&[base_pos, target_pos]),
+            );
+
+            let onclick = ctx.link().callback(move |_| ApiGraphMsg::EdgeClicked(from_idx, to_idx));
+            let onkeydown = on_activate_key(ctx.link().callback(move |_| ApiGraphMsg::EdgeClicked(from_idx, to_idx)));
+
+            let directed = if show_connectives && !outside_step {
+                directed_connectives(&self.positions, connectives, line)
+            } else {
+                vec![]
+            };
+
+            if directed.is_empty() {
+                return html! {
+                    <line
+                        x1={ from_x.to_string() }
+                        y1={ from_y.to_string() }
+                        x2={ to_x.to_string() }
+                        y2={ to_y.to_string() }
+                        stroke={ stroke }
+                        stroke-width={ stroke_width.to_string() }
+                        class={ class }
+                        onclick={ onclick }
+                        onkeydown={ onkeydown }
+                        tabindex="0"
+                        role="button"
+                        aria-label={ edge_aria_label(system, connectives, base_pos, target_pos) }
+                        aria-pressed={ is_selected.to_string() }
+                        style="cursor: pointer;"
+                    />
+                };
+            }
+
+            directed.iter().map(|conn| {
+                let control = conn.control_point();
+                // Stop the arrowhead at the edge of the target node
+                let end = toward(&conn.to, &control, node_radius + 2.0);
+
+                html! {
+                    <path
+                        d={ format!("M {} {} Q {} {} {} {}", conn.from.x, conn.from.y, control.x, control.y, end.x, end.y) }
+                        fill="none"
+                        stroke={ stroke.clone() }
+                        stroke-width={ stroke_width.to_string() }
+                        marker-end="url(#connective-arrow)"
+                        class={ classes!(class.clone(), "directed-edge") }
+                        onclick={ onclick.clone() }
+                        onkeydown={ onkeydown.clone() }
+                        tabindex="0"
+                        role="button"
+                        aria-label={ format!(
+                            "{} from {} to {}",
+                            conn.label, term_name(system, conn.base), term_name(system, conn.target),
+                        ) }
+                        aria-pressed={ is_selected.to_string() }
+                        style="cursor: pointer;"
+                    />
+                }
+            }).collect::<Html>()
+        }).collect::<Html>()
+    }
+
+    /// Render the edge legend, titled by the system's connective designation
+    pub(super) fn render_edge_legend(&self, system: &SystemView) -> Html {
+        let entries: Vec<_> = system.connectives.iter()
+            .filter_map(|conn| {
+                let label = conn.character.as_ref()?.value.as_str();
+                Some((conn.base_position.unwrap_or(0), conn.target_position.unwrap_or(0), label))
+            })
+            .collect();
+
+        if entries.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <section class="legend edge-legend">
+                <h3 class="legend-title">{ system.connective_legend_title() }</h3>
+                <ul>
+                    { for entries.iter().map(|(base, target, label)| html! {
+                        <li class="legend-item">
+                            <span class="legend-key">{ format!("{}→{}", base, target) }</span>
+                            <span class="legend-value">{ *label }</span>
+                        </li>
+                    })}
+                </ul>
+            </section>
+        }
+    }
+}
+
+/// Move `distance` from `point` toward `target`
+fn toward(point: &Point, target: &Point, distance: f64) -> Point {
+    let dx = target.x - point.x;
+    let dy = target.y - point.y;
+    let length = (dx * dx + dy * dy).sqrt().max(f64::EPSILON);
+    Point {
+        x: point.x + dx / length * distance,
+        y: point.y + dy / length * distance,
+    }
+}
@@ -0,0 +1,109 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::api::models::Link;
+use crate::core::decomposition::Decomposition;
+use super::{ApiGraphMsg, ApiGraphView};
+
+/// How the one-factorization / Hamiltonian decomposition is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorView {
+    Off,
+    /// Only the factor at this index, with its connective labels
+    Step(usize),
+    /// Every edge coloured by its factor
+    All,
+}
+
+impl ApiGraphView {
+    /// Render the factor mode picker and, when stepping, the previous/next stepper
+    pub(super) fn render_factor_controls(&self, ctx: &Context<Self>, decomposition: Option<&Decomposition>) -> Html {
+        let Some(decomposition) = decomposition else {
+            return html! {};
+        };
+        let factor_count = decomposition.factors.len();
+
+        let onchange = ctx.link().batch_callback(|e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            match select.value().as_str() {
+                "off" => Some(ApiGraphMsg::SetFactorView(FactorView::Off)),
+                "step" => Some(ApiGraphMsg::SetFactorView(FactorView::Step(0))),
+                "all" => Some(ApiGraphMsg::SetFactorView(FactorView::All)),
+                _ => None,
+            }
+        });
+
+        html! {
+            <div class="factor-controls">
+                <label class="layout-select">
+                    { decomposition.kind.label() }
+                    <select onchange={ onchange }>
+                        <option value="off" selected={ self.factor_view == FactorView::Off }>{"Off"}</option>
+                        <option value="step" selected={ matches!(self.factor_view, FactorView::Step(_)) }>{"Step through"}</option>
+                        <option value="all" selected={ self.factor_view == FactorView::All }>{"All at once"}</option>
+                    </select>
+                </label>
+                if let FactorView::Step(current) = self.factor_view {
+                    <div class="factor-stepper">
+                        <button
+                            disabled={ current == 0 }
+                            onclick={ ctx.link().callback(move |_| ApiGraphMsg::SetFactorView(FactorView::Step(current.saturating_sub(1)))) }
+                        >
+                            {"‹"}
+                        </button>
+                        <span>{ format!("{} {} of {}", decomposition.kind.factor_label(), current + 1, factor_count) }</span>
+                        <button
+                            disabled={ current + 1 >= factor_count }
+                            onclick={ ctx.link().callback(move |_| ApiGraphMsg::SetFactorView(FactorView::Step((current + 1).min(factor_count - 1)))) }
+                        >
+                            {"›"}
+                        </button>
+                    </div>
+                }
+            </div>
+        }
+    }
+
+    /// Render the factor legend; clicking a factor steps to it
+    pub(super) fn render_factor_legend(&self, ctx: &Context<Self>, decomposition: &Decomposition) -> Html {
+        if self.factor_view == FactorView::Off {
+            return html! {};
+        }
+
+        html! {
+            <section class="legend factor-legend">
+                <h3 class="legend-title">{ decomposition.kind.label() }</h3>
+                <ul>
+                    { for decomposition.factors.iter().enumerate().map(|(index, factor)| {
+                        let is_current = self.factor_view == FactorView::Step(index);
+                        let onclick = ctx.link().callback(move |_| ApiGraphMsg::SetFactorView(FactorView::Step(index)));
+                        let edges = factor.iter()
+                            .map(|e| format!("{}–{}", e.from + 1, e.to + 1))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        html! {
+                            <li
+                                class={ classes!("legend-item", "factor-item", is_current.then_some("selected")) }
+                                title={ edges }
+                                { onclick }
+                            >
+                                <span class="legend-swatch" style={ format!("background: {};", ctx.props().theme.edge_class(index)) }></span>
+                                <span class="legend-key">{ format!("{} {}", decomposition.kind.factor_label(), index + 1) }</span>
+                            </li>
+                        }
+                    })}
+                </ul>
+            </section>
+        }
+    }
+
+    /// Whether a line belongs to the factor being stepped through (always true outside step mode)
+    pub(super) fn is_line_in_current_factor(&self, decomposition: Option<&Decomposition>, line: &Link) -> bool {
+        let FactorView::Step(current) = self.factor_view else {
+            return true;
+        };
+        let (Some(base), Some(target)) = (line.base_position, line.target_position) else {
+            return false;
+        };
+        decomposition.and_then(|d| d.factor_of((base - 1) as usize, (target - 1) as usize)) == Some(current)
+    }
+}
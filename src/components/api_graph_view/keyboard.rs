@@ -0,0 +1,40 @@
+use gloo_events::EventListener;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+use crate::components::shortcuts::is_typing;
+use super::{ApiGraphMsg, ApiGraphView};
+
+/// Listen on the window for arrow keys and Escape, outside form fields, if keyboard
+/// navigation is on and there is a window to listen on
+pub(super) fn keyboard_listener(ctx: &Context<ApiGraphView>) -> Option<EventListener> {
+    if !ctx.props().keyboard_navigation {
+        return None;
+    }
+    let link = ctx.link().clone();
+    let window = web_sys::window()?;
+
+    Some(EventListener::new(&window, "keydown", move |event| {
+        let Some(e) = event.dyn_ref::<web_sys::KeyboardEvent>() else { return };
+        if is_typing(e) || e.ctrl_key() || e.meta_key() || e.alt_key() {
+            return;
+        }
+        let msg = match e.key().as_str() {
+            "ArrowRight" | "ArrowDown" => ApiGraphMsg::StepNode(1),
+            "ArrowLeft" | "ArrowUp" => ApiGraphMsg::StepNode(-1),
+            "Escape" => ApiGraphMsg::ClearSelection,
+            _ => return,
+        };
+        e.prevent_default();
+        link.send_message(msg);
+    }))
+}
+
+/// Activate a focused SVG element with Enter or Space, like a button
+pub(super) fn on_activate_key(callback: Callback<()>) -> Callback<KeyboardEvent> {
+    Callback::from(move |e: KeyboardEvent| {
+        if e.key() == "Enter" || e.key() == " " {
+            e.prevent_default();
+            callback.emit(());
+        }
+    })
+}
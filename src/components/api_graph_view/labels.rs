@@ -0,0 +1,120 @@
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::core::decomposition::Decomposition;
+use crate::core::geometry::{GeometryCalculator, Point};
+use crate::core::labels::{measure_text, LabelPlacement, LabelPlacer, LabelRequest};
+use super::connectives::{directed_connectives, ConnectiveIndex};
+use super::ApiGraphView;
+
+/// Edge label typography, used to measure label boxes
+const EDGE_LABEL_FONT_SIZE: f64 = 10.0;
+const EDGE_LABEL_PADDING: f64 = 4.0;
+
+/// An edge label placement with the node positions and labels it was made for. These
+/// change with the system, layout and which edges are labelled, so matching them is
+/// enough to reuse it.
+pub(super) struct PlacedLabels {
+    positions: Vec<Point>,
+    requests: Vec<LabelRequest>,
+    placements: Vec<LabelPlacement>,
+}
+
+impl ApiGraphView {
+    /// Render edge labels for connectives
+    /// Instead of iterating connectives independently, we iterate through lines
+    /// and find matching connectives to ensure labels align with the correct edges.
+    /// Labels are then run through a placement pass so they don't overlap on K5+.
+    pub(super) fn render_edge_labels(&self, ctx: &Context<Self>, system: &SystemView, connectives: &ConnectiveIndex, decomposition: Option<&Decomposition>) -> Html {
+        let theme = &ctx.props().theme;
+
+        let labels: Vec<(&str, LabelRequest)> = system.lines.iter()
+            .filter(|line| self.is_line_visible(system, line))
+            .filter(|line| self.is_line_in_current_factor(decomposition, line))
+            .flat_map(|line| directed_connectives(&self.positions, connectives, line))
+            .filter(|conn| !conn.label.is_empty())
+            .map(|conn| {
+                // Paired connectives are labelled along their own curve's apex
+                let offset = conn.normal();
+                let shift = conn.bend / 2.0;
+                let request = LabelRequest {
+                    from: Point { x: conn.from.x + offset.0 * shift, y: conn.from.y + offset.1 * shift },
+                    to: Point { x: conn.to.x + offset.0 * shift, y: conn.to.y + offset.1 * shift },
+                    width: measure_text(conn.label, EDGE_LABEL_FONT_SIZE) + EDGE_LABEL_PADDING * 2.0,
+                    height: 16.0,
+                };
+                (conn.label, request)
+            })
+            .collect();
+
+        let requests: Vec<LabelRequest> = labels.iter().map(|(_, r)| r.clone()).collect();
+        let placements = if self.animation.is_some() {
+            LabelPlacer::unplaced(&requests)
+        } else {
+            self.placed_labels(system, requests.clone())
+        };
+
+        labels.iter().zip(requests.iter()).zip(placements.iter()).map(|(((label, _), request), placement)| {
+            let rect_width = request.width;
+            let rect_height = request.height;
+
+            html! {
+                <>
+                    if placement.leader {
+                        <line
+                            x1={ placement.anchor.x.to_string() }
+                            y1={ placement.anchor.y.to_string() }
+                            x2={ placement.center.x.to_string() }
+                            y2={ placement.center.y.to_string() }
+                            stroke={ theme.leader }
+                            stroke-width="0.75"
+                            class="edge-label-leader"
+                            style="pointer-events: none;"
+                        />
+                    }
+                    <g class="edge-label-group" transform={ format!("translate({} {}) rotate({})", placement.center.x, placement.center.y, placement.angle) }>
+                        <rect
+                            x={ (-rect_width / 2.0).to_string() }
+                            y={ (-rect_height / 2.0).to_string() }
+                            width={ rect_width.to_string() }
+                            height={ rect_height.to_string() }
+                            fill={ theme.label_background }
+                            stroke={ theme.label_border }
+                            stroke-width="0.5"
+                            rx="4"
+                            style="pointer-events: none;"
+                        />
+                        <text
+                            x="0"
+                            y="0"
+                            text-anchor="middle"
+                            dominant-baseline="middle"
+                            class="edge-label"
+                            fill={ theme.label_text }
+                            style="font-size: 10px; font-weight: 500; pointer-events: none; user-select: none;"
+                        >
+                            { *label }
+                        </text>
+                    </g>
+                </>
+            }
+        }).collect::<Html>()
+    }
+
+    /// Place edge labels, reusing the last placement if the labels haven't changed
+    fn placed_labels(&self, system: &SystemView, requests: Vec<LabelRequest>) -> Vec<LabelPlacement> {
+        let mut cache = self.placed_labels.borrow_mut();
+        if let Some(ref placed) = *cache {
+            if placed.positions == self.positions && placed.requests == requests {
+                return placed.placements.clone();
+            }
+        }
+        let placements = LabelPlacer::new(self.positions.clone(), GeometryCalculator::node_radius_for(system.node_count()))
+            .place(&requests);
+        *cache = Some(PlacedLabels {
+            positions: self.positions.clone(),
+            requests,
+            placements: placements.clone(),
+        });
+        placements
+    }
+}
@@ -0,0 +1,593 @@
+mod aria;
+mod chords;
+mod connectives;
+mod edges;
+mod factors;
+mod keyboard;
+mod labels;
+mod nodes;
+mod overlays;
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::sync::atomic::Ordering;
+use gloo_events::EventListener;
+use gloo_timers::callback::Interval;
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::core::decomposition::Decomposition;
+use crate::core::geometry::{LayoutKind, OverlayKind, Point};
+use crate::core::system_config::ColorScheme;
+use crate::core::system_kind::SystemKind;
+use crate::core::theme::GraphTheme;
+use crate::core::symmetry::Permutation;
+use crate::components::adjacency_matrix::AdjacencyMatrix;
+use crate::components::subsystem_explorer::SubsystemExplorer;
+use crate::components::symmetry_panel::{SymmetryPanel, SymmetryReport};
+use aria::NEXT_INSTANCE;
+use chords::ChordClasses;
+use connectives::index_connectives;
+use keyboard::keyboard_listener;
+use labels::PlacedLabels;
+
+pub use factors::FactorView;
+
+/// Viewport centre and layout size used when laying out nodes locally
+const VIEWPORT_CENTER: f64 = 400.0;
+const LAYOUT_SIZE: f64 = 700.0;
+
+/// Layout transition length, in frames of ~16ms
+const LAYOUT_ANIMATION_FRAMES: u32 = 30;
+
+#[derive(Properties, PartialEq)]
+pub struct ApiGraphViewProps {
+    pub system: SystemView,
+    #[prop_or_default]
+    pub on_navigate: Option<Callback<SystemKind>>,
+    #[prop_or_default]
+    pub show_edge_labels: bool,
+    /// Magnification about the centre of the viewport
+    #[prop_or(1.0)]
+    pub zoom: f64,
+    /// Node selection to show, for views whose selection is driven from outside
+    #[prop_or_default]
+    pub selected_node: Option<usize>,
+    /// Emitted with the new node selection whenever a node is clicked
+    #[prop_or_default]
+    pub on_node_select: Option<Callback<Option<usize>>>,
+    /// Node or edge to select when it changes, e.g. after following a search result
+    #[prop_or_default]
+    pub focus: Option<Focus>,
+    /// Whether arrow keys step through the nodes and Escape clears the selection.
+    /// Turn it off while something else, like a dialog, owns the keyboard
+    #[prop_or(true)]
+    pub keyboard_navigation: bool,
+    #[prop_or_default]
+    pub theme: GraphTheme,
+    /// The system's own colours, which take over from the theme's selection colours
+    #[prop_or_default]
+    pub color_scheme: Option<ColorScheme>,
+    /// Layout to start in
+    #[prop_or_default]
+    pub layout: LayoutKind,
+    #[prop_or_default]
+    pub on_layout_change: Option<Callback<LayoutKind>>,
+    /// Emitted with the selected node or edge whenever the user changes it, in the same
+    /// terms as `focus`
+    #[prop_or_default]
+    pub on_selection_change: Option<Callback<Option<Focus>>>,
+}
+
+/// A node or edge to bring into focus, as 0-based indices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Focus {
+    Node(usize),
+    Edge(usize, usize),
+}
+
+pub enum ApiGraphMsg {
+    NodeClicked(usize),
+    EdgeClicked(usize, usize),
+    ToggleOverlay(OverlayKind),
+    SelectLayout(LayoutKind),
+    AnimationFrame,
+    ToggleChordColouring,
+    ToggleMatrix,
+    ToggleChordClass(usize),
+    SetFactorView(FactorView),
+    HoverSubset(Option<Vec<i32>>),
+    SelectSubset(Option<Vec<i32>>),
+    /// Move the terms by a permutation of their current positions
+    ApplyPermutation(String, Permutation),
+    ResetPermutation,
+    /// Move the node selection this many places in position order
+    StepNode(isize),
+    ClearSelection,
+}
+
+/// An in-progress transition between two sets of node positions
+struct LayoutAnimation {
+    from: Vec<Point>,
+    to: Vec<Point>,
+    frame: u32,
+    /// Term permutation and positions to settle on once the animation ends
+    commit: Option<(Permutation, Vec<Point>)>,
+    _ticker: Interval,
+}
+
+pub struct ApiGraphView {
+    selected_node: Option<usize>,
+    selected_edge: Option<(usize, usize)>,
+    overlays: HashSet<OverlayKind>,
+    layout: LayoutKind,
+    /// Node positions currently on screen, indexed by position - 1
+    positions: Vec<Point>,
+    animation: Option<LayoutAnimation>,
+    chords: ChordClasses,
+    show_matrix: bool,
+    factor_view: FactorView,
+    /// Sub-system (positions) pinned from the explorer
+    selected_subset: Option<Vec<i32>>,
+    /// Sub-system under the pointer in the explorer, shown over the pinned one
+    hovered_subset: Option<Vec<i32>>,
+    /// Where each term currently sits relative to the fetched system
+    term_permutation: Permutation,
+    /// The system with its terms moved by `term_permutation`, as drawn
+    permuted: Rc<SystemView>,
+    /// The last edge label placement, reused while nothing it depends on changes
+    placed_labels: RefCell<Option<PlacedLabels>>,
+    symmetry_report: Option<SymmetryReport>,
+    _keydown: Option<EventListener>,
+    /// Unique per view, for the ids tying the SVG to its text description
+    instance: usize,
+}
+
+impl Component for ApiGraphView {
+    type Message = ApiGraphMsg;
+    type Properties = ApiGraphViewProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let layout = ctx.props().layout;
+        let mut view = Self {
+            selected_node: ctx.props().selected_node,
+            selected_edge: None,
+            overlays: HashSet::from([OverlayKind::SymbolicCircles]),
+            layout,
+            positions: layout_positions(&ctx.props().system, layout),
+            animation: None,
+            chords: ChordClasses::default(),
+            show_matrix: false,
+            factor_view: FactorView::Off,
+            selected_subset: None,
+            hovered_subset: None,
+            term_permutation: Permutation::identity(ctx.props().system.node_count()),
+            permuted: Rc::new(ctx.props().system.clone()),
+            placed_labels: RefCell::new(None),
+            symmetry_report: None,
+            _keydown: keyboard_listener(ctx),
+            instance: NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed),
+        };
+        if let Some(focus) = ctx.props().focus {
+            view.apply_focus(focus);
+        }
+        view
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().selected_node != old_props.selected_node {
+            self.selected_node = ctx.props().selected_node;
+        }
+        if ctx.props().keyboard_navigation != old_props.keyboard_navigation {
+            self._keydown = keyboard_listener(ctx);
+        }
+
+        // A new system snaps straight to the chosen layout
+        let system_changed = ctx.props().system != old_props.system;
+        if system_changed {
+            self.positions = layout_positions(&ctx.props().system, self.layout);
+            self.animation = None;
+            self.chords.hidden_steps.clear();
+            if self.factor_view != FactorView::Off {
+                self.factor_view = FactorView::Step(0);
+            }
+            self.selected_subset = None;
+            self.hovered_subset = None;
+            self.set_permutation(ctx, Permutation::identity(ctx.props().system.node_count()));
+            self.symmetry_report = None;
+        }
+
+        if let Some(focus) = ctx.props().focus {
+            if system_changed || ctx.props().focus != old_props.focus {
+                self.apply_focus(focus);
+            }
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            ApiGraphMsg::NodeClicked(idx) => {
+                // Toggle selection
+                if self.selected_node == Some(idx) {
+                    self.selected_node = None;
+                } else {
+                    self.selected_node = Some(idx);
+                    self.selected_edge = None;
+                }
+                if let Some(ref on_node_select) = ctx.props().on_node_select {
+                    on_node_select.emit(self.selected_node);
+                }
+                self.emit_selection(ctx);
+                true
+            }
+            ApiGraphMsg::EdgeClicked(from, to) => {
+                let edge = if from < to { (from, to) } else { (to, from) };
+                if self.selected_edge == Some(edge) {
+                    self.selected_edge = None;
+                } else {
+                    self.selected_edge = Some(edge);
+                    if self.selected_node.take().is_some() {
+                        if let Some(ref on_node_select) = ctx.props().on_node_select {
+                            on_node_select.emit(None);
+                        }
+                    }
+                }
+                self.emit_selection(ctx);
+                true
+            }
+            ApiGraphMsg::ToggleOverlay(kind) => {
+                if !self.overlays.remove(&kind) {
+                    self.overlays.insert(kind);
+                }
+                true
+            }
+            ApiGraphMsg::SelectLayout(layout) => {
+                if layout == self.layout {
+                    return false;
+                }
+                self.layout = layout;
+                if let Some(ref on_layout_change) = ctx.props().on_layout_change {
+                    on_layout_change.emit(layout);
+                }
+                // Chord classes are steps around the polygon, so they lapse off it
+                let chords_lapsed = self.chords.colouring && !layout.has_cyclic_order();
+                if chords_lapsed {
+                    self.chords = ChordClasses::default();
+                }
+                // A pending term permutation lands first; a layout change in flight
+                // just continues from where the nodes are
+                if self.animation.as_ref().is_some_and(|a| a.commit.is_some()) {
+                    self.settle_animation(ctx);
+                }
+
+                let link = ctx.link().clone();
+                self.animation = Some(LayoutAnimation {
+                    from: self.positions.clone(),
+                    to: layout_positions(&ctx.props().system, layout),
+                    frame: 0,
+                    commit: None,
+                    _ticker: Interval::new(16, move || link.send_message(ApiGraphMsg::AnimationFrame)),
+                });
+                chords_lapsed
+            }
+            ApiGraphMsg::AnimationFrame => {
+                let Some(ref mut animation) = self.animation else {
+                    return false;
+                };
+                animation.frame += 1;
+
+                let t = (animation.frame as f64 / LAYOUT_ANIMATION_FRAMES as f64).min(1.0);
+                let eased = t * t * (3.0 - 2.0 * t);
+                self.positions = animation.from.iter().zip(animation.to.iter())
+                    .map(|(from, to)| Point {
+                        x: from.x + (to.x - from.x) * eased,
+                        y: from.y + (to.y - from.y) * eased,
+                    })
+                    .collect();
+
+                if animation.frame >= LAYOUT_ANIMATION_FRAMES {
+                    self.settle_animation(ctx);
+                }
+                true
+            }
+            ApiGraphMsg::ToggleChordColouring => {
+                if !self.layout.has_cyclic_order() {
+                    return false;
+                }
+                self.chords.toggle_colouring();
+                true
+            }
+            ApiGraphMsg::ToggleMatrix => {
+                self.show_matrix = !self.show_matrix;
+                true
+            }
+            ApiGraphMsg::ToggleChordClass(step) => {
+                self.chords.toggle_step(step);
+                true
+            }
+            ApiGraphMsg::SetFactorView(view) => {
+                self.factor_view = view;
+                true
+            }
+            ApiGraphMsg::HoverSubset(subset) => {
+                self.hovered_subset = subset;
+                true
+            }
+            ApiGraphMsg::SelectSubset(subset) => {
+                self.selected_subset = subset;
+                true
+            }
+            ApiGraphMsg::ApplyPermutation(label, action) => {
+                self.settle_animation(ctx);
+                let system = &ctx.props().system;
+                self.symmetry_report = Some(SymmetryReport {
+                    action: label,
+                    changes: self.permuted.connective_changes(&action),
+                });
+
+                // Glide each node to the slot its term is moving to, then
+                // hand the term over to that position
+                let settled = layout_positions(system, self.layout);
+                let to = (0..self.positions.len())
+                    .map(|i| settled.get(action.apply(i)).unwrap_or(&self.positions[i]).clone())
+                    .collect();
+                let link = ctx.link().clone();
+                self.animation = Some(LayoutAnimation {
+                    from: self.positions.clone(),
+                    to,
+                    frame: 0,
+                    commit: Some((action.after(&self.term_permutation), settled)),
+                    _ticker: Interval::new(16, move || link.send_message(ApiGraphMsg::AnimationFrame)),
+                });
+                true
+            }
+            ApiGraphMsg::StepNode(delta) => {
+                let n = ctx.props().system.node_count() as isize;
+                if n == 0 {
+                    return false;
+                }
+                let next = match self.selected_node {
+                    Some(idx) => (idx as isize + delta).rem_euclid(n),
+                    None if delta > 0 => 0,
+                    None => n - 1,
+                };
+                self.selected_node = Some(next as usize);
+                self.selected_edge = None;
+                if let Some(ref on_node_select) = ctx.props().on_node_select {
+                    on_node_select.emit(self.selected_node);
+                }
+                self.emit_selection(ctx);
+                true
+            }
+            ApiGraphMsg::ClearSelection => {
+                if self.selected_node.is_none() && self.selected_edge.is_none() {
+                    return false;
+                }
+                if self.selected_node.take().is_some() {
+                    if let Some(ref on_node_select) = ctx.props().on_node_select {
+                        on_node_select.emit(None);
+                    }
+                }
+                self.selected_edge = None;
+                self.emit_selection(ctx);
+                true
+            }
+            ApiGraphMsg::ResetPermutation => {
+                self.settle_animation(ctx);
+                self.set_permutation(ctx, Permutation::identity(ctx.props().system.node_count()));
+                self.symmetry_report = None;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        // Nodes, edges and labels are drawn at `self.positions` so they follow the layout
+        let system = &*self.permuted;
+        let decomposition = Decomposition::for_order(system.node_count());
+        let connectives = index_connectives(system);
+        // Stepping through factors always labels the factor's connectives
+        let show_edge_labels = ctx.props().show_edge_labels
+            || matches!(self.factor_view, FactorView::Step(_));
+
+        html! {
+            <div class="graph-view">
+                <div class="graph-controls">
+                    { self.render_layout_control(ctx) }
+                    { self.render_overlay_controls(ctx) }
+                    { self.render_chord_toggle(ctx) }
+                    <label class="overlay-toggle">
+                        <input
+                            type="checkbox"
+                            checked={ self.show_matrix }
+                            onchange={ ctx.link().callback(|_| ApiGraphMsg::ToggleMatrix) }
+                        />
+                        {"Matrix"}
+                    </label>
+                    { self.render_factor_controls(ctx, decomposition.as_ref()) }
+                </div>
+                <svg
+                    class="graph-svg"
+                    viewBox={ zoomed_view_box(ctx.props().zoom) }
+                    preserveAspectRatio="xMidYMid meet"
+                    role="group"
+                    aria-label={ format!("{} ({}) graph", system.display_name(), system.k_notation()) }
+                    aria-describedby={ self.description_id() }
+                >
+                    <defs>
+                        <marker
+                            id="connective-arrow"
+                            viewBox="0 0 10 10"
+                            refX="10"
+                            refY="5"
+                            markerWidth="7"
+                            markerHeight="7"
+                            orient="auto"
+                        >
+                            <path d="M 0 0 L 10 5 L 0 10 z" fill={ ctx.props().theme.edge } />
+                        </marker>
+                    </defs>
+                    <g aria-hidden="true">
+                        { self.render_overlays(ctx) }
+                    </g>
+                    { self.render_edges(ctx, system, &connectives, decomposition.as_ref(), show_edge_labels) }
+                    if show_edge_labels {
+                        <g aria-hidden="true">
+                            { self.render_edge_labels(ctx, system, &connectives, decomposition.as_ref()) }
+                        </g>
+                    }
+                    { self.render_nodes(ctx, system, &connectives) }
+                </svg>
+                { self.render_description(system) }
+                <div class="sr-only" aria-live="polite" aria-atomic="true">
+                    { self.selection_announcement(system, &connectives) }
+                </div>
+                if self.show_matrix {
+                    <AdjacencyMatrix
+                        system={ self.permuted.clone() }
+                        selected_node={ self.selected_node }
+                        selected_edge={ self.selected_edge }
+                        on_select_node={ ctx.link().callback(ApiGraphMsg::NodeClicked) }
+                        on_select_edge={ ctx.link().callback(|(from, to)| ApiGraphMsg::EdgeClicked(from, to)) }
+                    />
+                }
+                <div class="graph-legends">
+                    { self.render_node_legend(ctx, system) }
+                    if self.chords.colouring {
+                        { self.render_chord_legend(ctx, system) }
+                    }
+                    if let Some(ref decomposition) = decomposition {
+                        { self.render_factor_legend(ctx, decomposition) }
+                    }
+                    if show_edge_labels {
+                        { self.render_edge_legend(system) }
+                    }
+                    <SubsystemExplorer
+                        key={ system.order }
+                        system={ self.permuted.clone() }
+                        selected={ self.selected_subset.clone() }
+                        on_hover={ ctx.link().callback(ApiGraphMsg::HoverSubset) }
+                        on_select={ ctx.link().callback(ApiGraphMsg::SelectSubset) }
+                        on_navigate={ ctx.props().on_navigate.clone() }
+                    />
+                    <SymmetryPanel
+                        node_count={ system.node_count() }
+                        current={ self.term_permutation.clone() }
+                        report={ self.symmetry_report.clone() }
+                        on_apply={ ctx.link().callback(|(label, action)| ApiGraphMsg::ApplyPermutation(label, action)) }
+                        on_reset={ ctx.link().callback(|_| ApiGraphMsg::ResetPermutation) }
+                    />
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ApiGraphView {
+    /// Select the focused node or edge. Nodes follow their term through any permutation;
+    /// edges belong to positions and stay put.
+    fn apply_focus(&mut self, focus: Focus) {
+        match focus {
+            Focus::Node(idx) => {
+                self.selected_node = Some(self.term_permutation.apply(idx));
+                self.selected_edge = None;
+            }
+            Focus::Edge(a, b) => {
+                self.selected_edge = Some((a.min(b), a.max(b)));
+                self.selected_node = None;
+            }
+        }
+    }
+
+    /// Report the selection to `on_selection_change` as a focus, undoing any term permutation
+    /// so that it selects the same term when applied to the fetched system
+    fn emit_selection(&self, ctx: &Context<Self>) {
+        let Some(ref on_selection_change) = ctx.props().on_selection_change else { return };
+        let focus = if let Some(idx) = self.selected_node {
+            let term = (0..ctx.props().system.node_count())
+                .find(|&i| self.term_permutation.apply(i) == idx)
+                .unwrap_or(idx);
+            Some(Focus::Node(term))
+        } else {
+            self.selected_edge.map(|(a, b)| Focus::Edge(a, b))
+        };
+        on_selection_change.emit(focus);
+    }
+
+    /// Finish any running animation at once, committing a pending term permutation
+    fn settle_animation(&mut self, ctx: &Context<Self>) {
+        if let Some(animation) = self.animation.take() {
+            match animation.commit {
+                Some((permutation, positions)) => {
+                    self.set_permutation(ctx, permutation);
+                    self.positions = positions;
+                }
+                None => self.positions = animation.to,
+            }
+        }
+    }
+
+    /// Move the terms by `permutation`, keeping the drawn system in step
+    fn set_permutation(&mut self, ctx: &Context<Self>, permutation: Permutation) {
+        self.permuted = Rc::new(ctx.props().system.with_permuted_terms(&permutation));
+        self.term_permutation = permutation;
+    }
+
+    /// Where the node at `position` (1-based) currently sits on screen
+    pub(super) fn node_point(&self, position: i32) -> Option<&Point> {
+        let index = usize::try_from(position - 1).ok()?;
+        self.positions.get(index)
+    }
+
+    /// Render the layout picker
+    fn render_layout_control(&self, ctx: &Context<Self>) -> Html {
+        let onchange = ctx.link().batch_callback(|e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            LayoutKind::from_key(&select.value()).map(ApiGraphMsg::SelectLayout)
+        });
+
+        html! {
+            <label class="layout-select">
+                {"Layout"}
+                <select onchange={ onchange }>
+                    { for LayoutKind::ALL.iter().map(|kind| html! {
+                        <option value={ kind.key() } selected={ *kind == self.layout }>
+                            { kind.label() }
+                        </option>
+                    })}
+                </select>
+            </label>
+        }
+    }
+
+    /// Highlight class for an element spanning `positions`, relative to the active sub-system
+    pub(super) fn subset_class(&self, positions: &[i32]) -> Option<&'static str> {
+        let subset = self.hovered_subset.as_ref().or(self.selected_subset.as_ref())?;
+        if positions.iter().all(|p| subset.contains(p)) {
+            Some("in-subset")
+        } else {
+            Some("dimmed")
+        }
+    }
+}
+
+/// SVG viewBox for the 800×800 viewport magnified `zoom` times about its centre
+fn zoomed_view_box(zoom: f64) -> String {
+    let size = 800.0 / zoom.max(0.1);
+    let origin = VIEWPORT_CENTER - size / 2.0;
+    format!("{} {} {} {}", origin, origin, size, size)
+}
+
+/// Node positions for a system under a layout, indexed by position - 1
+fn layout_positions(system: &SystemView, layout: LayoutKind) -> Vec<Point> {
+    let node_count = system.node_count();
+    let provided: Vec<Option<Point>> = (1..=node_count as i32)
+        .map(|position| system.coordinate_at(position).map(|c| Point { x: c.x, y: c.y }))
+        .collect();
+    let center = Point { x: VIEWPORT_CENTER, y: VIEWPORT_CENTER };
+
+    layout.layout().positions(node_count, &provided, &center, LAYOUT_SIZE)
+}
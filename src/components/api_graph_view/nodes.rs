@@ -0,0 +1,136 @@
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::core::colour::readable_text_on;
+use crate::core::geometry::GeometryCalculator;
+use super::aria::node_aria_label;
+use super::connectives::ConnectiveIndex;
+use super::keyboard::on_activate_key;
+use super::{ApiGraphMsg, ApiGraphView};
+
+impl ApiGraphView {
+    /// Render nodes from coordinates and terms
+    pub(super) fn render_nodes(&self, ctx: &Context<Self>, system: &SystemView, connectives: &ConnectiveIndex) -> Html {
+        let theme = &ctx.props().theme;
+        let node_radius = GeometryCalculator::node_radius_for(system.node_count());
+
+        system.coordinates.iter().filter_map(|coord| {
+            let position = coord.position;
+            let idx = (position - 1) as usize;  // Convert 1-based position to 0-based index
+            let point = self.node_point(position)?;
+
+            let is_selected = self.selected_node == Some(idx);
+
+            // Get color for this node from colours array, or use default
+            let fill = if is_selected {
+                ctx.props().color_scheme.as_ref()
+                    .map_or(theme.selected_node, |scheme| scheme.selected_node.as_str())
+                    .to_string()
+            } else {
+                system.colour_at(position)
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| theme.node.to_string())
+            };
+            // Keep the position number legible whatever the fill
+            let (number_fill, number_outline) = readable_text_on(&fill)
+                .unwrap_or((theme.node_text, theme.node_text_outline));
+
+            let radius = if is_selected { node_radius * 1.5 } else { node_radius };
+            let onclick = ctx.link().callback(move |_| ApiGraphMsg::NodeClicked(idx));
+            let onkeydown = on_activate_key(ctx.link().callback(move |_| ApiGraphMsg::NodeClicked(idx)));
+
+            // Get term label for this position
+            let term = system.term_at(position).unwrap_or("");
+
+            // Positions laid out locally (not provided by the API) get a dashed outline
+            let stroke_dasharray = coord.synthesized.then_some("3 2");
+            let class = classes!(
+                "node",
+                coord.synthesized.then_some("synthesized"),
+                self.subset_class(&[position]),
+            );
+
+            Some(html! {
+                <g
+                    class={ class }
+                    onclick={ onclick }
+                    onkeydown={ onkeydown }
+                    tabindex="0"
+                    role="button"
+                    aria-label={ node_aria_label(system, connectives, position) }
+                    aria-pressed={ is_selected.to_string() }
+                >
+                    if coord.synthesized {
+                        <title>{ format!("Position {} was laid out locally: the API returned no coordinate", position) }</title>
+                    }
+                    <circle
+                        cx={ point.x.to_string() }
+                        cy={ point.y.to_string() }
+                        r={ radius.to_string() }
+                        fill={ fill }
+                        stroke={ theme.node_stroke }
+                        stroke-width="2"
+                        stroke-dasharray={ stroke_dasharray }
+                        style="cursor: pointer;"
+                    />
+                    <text
+                        aria-hidden="true"
+                        x={ point.x.to_string() }
+                        y={ point.y.to_string() }
+                        text-anchor="middle"
+                        dominant-baseline="middle"
+                        fill={ number_fill }
+                        stroke={ number_outline }
+                        stroke-width="1"
+                        paint-order="stroke"
+                        style="font-size: 12px; font-weight: bold; pointer-events: none; user-select: none;"
+                    >
+                        { position }
+                    </text>
+                    // Render vocabulary label if available
+                    if !term.is_empty() {
+                        <text
+                            aria-hidden="true"
+                            x={ point.x.to_string() }
+                            y={ (point.y + radius + 16.0).to_string() }
+                            text-anchor="middle"
+                            dominant-baseline="middle"
+                            fill={ theme.term_text }
+                            style="font-size: 14px; font-weight: 500; pointer-events: none; user-select: none;"
+                        >
+                            { term }
+                        </text>
+                    }
+                </g>
+            })
+        }).collect::<Html>()
+    }
+
+    /// Render the node legend, titled by the system's term designation
+    pub(super) fn render_node_legend(&self, ctx: &Context<Self>, system: &SystemView) -> Html {
+        if system.terms.is_empty() {
+            return html! {};
+        }
+
+        let mut terms: Vec<_> = system.terms.iter().collect();
+        terms.sort_by_key(|t| t.position);
+
+        html! {
+            <section class="legend node-legend">
+                <h3 class="legend-title">{ system.term_legend_title() }</h3>
+                <ul>
+                    { for terms.iter().map(|term| {
+                        let colour = system.colour_at(term.position).unwrap_or(ctx.props().theme.node);
+                        let value = term.character.as_ref().map(|c| c.value.as_str()).unwrap_or("");
+                        html! {
+                            <li class="legend-item">
+                                <span class="legend-swatch" style={ format!("background: {};", colour) }></span>
+                                <span class="legend-key">{ term.position }</span>
+                                <span class="legend-value">{ value }</span>
+                            </li>
+                        }
+                    })}
+                </ul>
+            </section>
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use yew::prelude::*;
+use crate::core::geometry::{GeometryCalculator, OverlayKind};
+use super::{ApiGraphMsg, ApiGraphView};
+
+/// Radius of the monad's symbolic circle: half the 600px drawable area of the viewport
+const MONAD_CIRCLE_RADIUS: f64 = 300.0;
+
+impl ApiGraphView {
+    /// Render the toggles for geometric overlays
+    pub(super) fn render_overlay_controls(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="overlay-controls">
+                { for OverlayKind::ALL.iter().map(|&kind| {
+                    let onchange = ctx.link().callback(move |_| ApiGraphMsg::ToggleOverlay(kind));
+                    html! {
+                        <label class="overlay-toggle">
+                            <input
+                                type="checkbox"
+                                checked={ self.overlays.contains(&kind) }
+                                onchange={ onchange }
+                            />
+                            { kind.label() }
+                        </label>
+                    }
+                })}
+            </div>
+        }
+    }
+
+    /// Render the enabled geometric overlays, derived from the current node positions
+    pub(super) fn render_overlays(&self, ctx: &Context<Self>) -> Html {
+        let overlay_color = ctx.props().theme.overlay;
+        let points = &self.positions;
+
+        let mut layers = Vec::new();
+
+        if self.overlays.contains(&OverlayKind::SymbolicCircles) {
+            for circle in GeometryCalculator::symbolic_circles_for_points(points, MONAD_CIRCLE_RADIUS) {
+                layers.push(html! {
+                    <circle
+                        cx={ circle.center.x.to_string() }
+                        cy={ circle.center.y.to_string() }
+                        r={ circle.radius.to_string() }
+                        fill="none"
+                        stroke={ overlay_color }
+                        stroke-width="2"
+                        class="symbolic-circle"
+                    />
+                });
+            }
+        }
+
+        if self.overlays.contains(&OverlayKind::EnclosingCircle) {
+            if let Some(circle) = GeometryCalculator::enclosing_circle(points) {
+                layers.push(html! {
+                    <circle
+                        cx={ circle.center.x.to_string() }
+                        cy={ circle.center.y.to_string() }
+                        r={ circle.radius.to_string() }
+                        fill="none"
+                        stroke={ overlay_color }
+                        stroke-width="1"
+                        stroke-dasharray="6 4"
+                        class="overlay overlay-enclosing-circle"
+                    />
+                });
+            }
+        }
+
+        if self.overlays.contains(&OverlayKind::InscribedPolygon) {
+            let polygon = GeometryCalculator::inscribed_polygon(points);
+            if !polygon.is_empty() {
+                let points_attr = polygon.iter()
+                    .map(|p| format!("{},{}", p.x, p.y))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                layers.push(html! {
+                    <polygon
+                        points={ points_attr }
+                        fill={ overlay_color }
+                        fill-opacity="0.06"
+                        stroke={ overlay_color }
+                        stroke-width="1.5"
+                        class="overlay overlay-polygon"
+                    />
+                });
+            }
+        }
+
+        if self.overlays.contains(&OverlayKind::Centroid) {
+            if let Some(center) = GeometryCalculator::centroid(points) {
+                layers.push(html! {
+                    <g class="overlay overlay-centroid">
+                        <line
+                            x1={ (center.x - 6.0).to_string() }
+                            y1={ center.y.to_string() }
+                            x2={ (center.x + 6.0).to_string() }
+                            y2={ center.y.to_string() }
+                            stroke={ overlay_color }
+                            stroke-width="1.5"
+                        />
+                        <line
+                            x1={ center.x.to_string() }
+                            y1={ (center.y - 6.0).to_string() }
+                            x2={ center.x.to_string() }
+                            y2={ (center.y + 6.0).to_string() }
+                            stroke={ overlay_color }
+                            stroke-width="1.5"
+                        />
+                    </g>
+                });
+            }
+        }
+
+        html! { <g class="overlays">{ for layers }</g> }
+    }
+}
@@ -0,0 +1,103 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use crate::api::glossary::{Glossary, GlossaryEntry, Occurrence};
+use crate::api::models::SystemView;
+use crate::components::api_graph_view::Focus;
+use crate::core::system_kind::SystemKind;
+
+#[derive(Properties, PartialEq)]
+pub struct GlossaryViewProps {
+    pub systems: Vec<SystemView>,
+    /// Emitted when an occurrence is clicked, to open its system with it selected
+    pub on_open: Callback<(SystemKind, Focus)>,
+}
+
+/// Every character in the loaded systems, by language, with links to where it occurs
+#[function_component(GlossaryView)]
+pub fn glossary_view(props: &GlossaryViewProps) -> Html {
+    let glossary = use_memo(props.systems.clone(), |systems| Glossary::build(systems));
+    let filter = use_state(String::new);
+
+    let on_filter_input = {
+        let filter = filter.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            filter.set(input.value());
+        })
+    };
+
+    if glossary.is_empty() {
+        return html! { <div class="loading">{"No characters loaded yet"}</div> };
+    }
+
+    let needle = filter.trim().to_lowercase();
+    let matches = |entry: &&GlossaryEntry| {
+        needle.is_empty()
+            || entry.character.value.to_lowercase().contains(&needle)
+            || entry.character.id.to_lowercase().contains(&needle)
+    };
+
+    html! {
+        <section class="glossary">
+            <div class="glossary-controls">
+                <input
+                    type="search"
+                    class="glossary-filter"
+                    placeholder="Filter characters…"
+                    value={ (*filter).clone() }
+                    oninput={ on_filter_input }
+                />
+                <span class="glossary-count">{ format!("{} characters", glossary.len()) }</span>
+            </div>
+            { for glossary.by_language().into_iter().map(|(language, entries)| {
+                let entries: Vec<&GlossaryEntry> = entries.into_iter().filter(matches).collect();
+                if entries.is_empty() {
+                    return html! {};
+                }
+
+                html! {
+                    <div class="legend glossary-language">
+                        <h3 class="legend-title">{ format!("{} ({})", language.label(), entries.len()) }</h3>
+                        <dl>
+                            { for entries.iter().map(|entry| render_entry(entry, &props.on_open)) }
+                        </dl>
+                    </div>
+                }
+            })}
+        </section>
+    }
+}
+
+fn render_entry(entry: &GlossaryEntry, on_open: &Callback<(SystemKind, Focus)>) -> Html {
+    html! {
+        <div class="glossary-entry">
+            <dt>
+                <span class="glossary-value">{ &entry.character.value }</span>
+                <span class="glossary-id">{ &entry.character.id }</span>
+            </dt>
+            <dd>
+                { for entry.occurrences.iter().filter_map(|occurrence| {
+                    let kind = SystemKind::from_order(occurrence.order().max(0) as usize)?;
+                    let (focus, label) = match *occurrence {
+                        Occurrence::Term { position, .. } => (
+                            Focus::Node((position - 1) as usize),
+                            format!("{} · term {}", kind.display_name(), position),
+                        ),
+                        Occurrence::Connective { base, target, .. } => (
+                            Focus::Edge((base - 1) as usize, (target - 1) as usize),
+                            format!("{} · {}→{}", kind.display_name(), base, target),
+                        ),
+                    };
+                    let onclick = {
+                        let on_open = on_open.clone();
+                        Callback::from(move |_| on_open.emit((kind, focus)))
+                    };
+
+                    Some(html! {
+                        <button class="glossary-occurrence" onclick={ onclick }>{ label }</button>
+                    })
+                })}
+            </dd>
+        </div>
+    }
+}
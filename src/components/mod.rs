@@ -1,3 +1,12 @@
-pub mod graph_view;
 pub mod api_graph_view;
 pub mod system_selector;
+pub mod system_header;
+pub mod subsystem_explorer;
+pub mod symmetry_panel;
+pub mod adjacency_matrix;
+pub mod slice_browser;
+pub mod system_gallery;
+pub mod system_comparison;
+pub mod glossary_view;
+pub mod search_palette;
+pub mod shortcuts;
@@ -0,0 +1,215 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::components::api_graph_view::Focus;
+use crate::core::fuzzy::fuzzy_score;
+use crate::core::system_kind::SystemKind;
+
+/// Results shown at once
+const MAX_RESULTS: usize = 50;
+
+/// What a search result refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchKind {
+    System,
+    Coherence,
+    Term,
+    Connective,
+}
+
+impl SearchKind {
+    fn label(&self) -> &'static str {
+        match self {
+            SearchKind::System => "System",
+            SearchKind::Coherence => "Coherence",
+            SearchKind::Term => "Term",
+            SearchKind::Connective => "Connective",
+        }
+    }
+}
+
+/// One searchable string and where choosing it leads
+#[derive(Debug, Clone, PartialEq)]
+struct SearchItem {
+    text: String,
+    detail: String,
+    kind: SearchKind,
+    system: SystemKind,
+    focus: Option<Focus>,
+}
+
+fn index_systems(systems: &[SystemView]) -> Vec<SearchItem> {
+    let mut items = Vec::new();
+
+    for system in systems {
+        let Some(kind) = system.kind() else { continue };
+        let name = system.display_name();
+
+        items.push(SearchItem {
+            text: name.clone(),
+            detail: system.k_notation(),
+            kind: SearchKind::System,
+            system: kind,
+            focus: None,
+        });
+        if let Some(ref coherence) = system.coherence {
+            items.push(SearchItem {
+                text: coherence.clone(),
+                detail: name.clone(),
+                kind: SearchKind::Coherence,
+                system: kind,
+                focus: None,
+            });
+        }
+        for term in &system.terms {
+            if let Some(ref character) = term.character {
+                items.push(SearchItem {
+                    text: character.value.clone(),
+                    detail: format!("{} · term {}", name, term.position),
+                    kind: SearchKind::Term,
+                    system: kind,
+                    focus: Some(Focus::Node((term.position - 1) as usize)),
+                });
+            }
+        }
+        for conn in &system.connectives {
+            if let (Some(character), Some(base), Some(target)) =
+                (conn.character.as_ref(), conn.base_position, conn.target_position)
+            {
+                items.push(SearchItem {
+                    text: character.value.clone(),
+                    detail: format!("{} · {}→{}", name, base, target),
+                    kind: SearchKind::Connective,
+                    system: kind,
+                    focus: Some(Focus::Edge((base - 1) as usize, (target - 1) as usize)),
+                });
+            }
+        }
+    }
+    items
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SearchPaletteProps {
+    pub systems: Vec<SystemView>,
+    /// Emitted with the chosen result's system and the node or edge to select, if any
+    pub on_choose: Callback<(SystemKind, Option<Focus>)>,
+    pub on_close: Callback<()>,
+}
+
+/// Command-palette style fuzzy search over system names, coherences, terms and connectives
+#[function_component(SearchPalette)]
+pub fn search_palette(props: &SearchPaletteProps) -> Html {
+    let index = use_memo(props.systems.clone(), |systems| index_systems(systems));
+    let query = use_state(String::new);
+    let active = use_state(|| 0usize);
+    let input_ref = use_node_ref();
+
+    {
+        let input_ref = input_ref.clone();
+        use_effect_with((), move |_| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let _ = input.focus();
+            }
+        });
+    }
+
+    let mut scored: Vec<(i32, &SearchItem)> = index.iter()
+        .filter_map(|item| fuzzy_score(&query, &item.text).map(|score| (score, item)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.system.cmp(&b.1.system)));
+    let results: Vec<SearchItem> = scored.into_iter()
+        .take(MAX_RESULTS)
+        .map(|(_, item)| item.clone())
+        .collect();
+    let active_index = (*active).min(results.len().saturating_sub(1));
+
+    let choose = {
+        let on_choose = props.on_choose.clone();
+        move |item: &SearchItem| on_choose.emit((item.system, item.focus))
+    };
+
+    let on_input = {
+        let query = query.clone();
+        let active = active.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            query.set(input.value());
+            active.set(0);
+        })
+    };
+
+    let on_keydown = {
+        let active = active.clone();
+        let results = results.clone();
+        let choose = choose.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "ArrowDown" => {
+                e.prevent_default();
+                active.set((active_index + 1).min(results.len().saturating_sub(1)));
+            }
+            "ArrowUp" => {
+                e.prevent_default();
+                active.set(active_index.saturating_sub(1));
+            }
+            "Enter" => {
+                if let Some(item) = results.get(active_index) {
+                    choose(item);
+                }
+            }
+            "Escape" => on_close.emit(()),
+            _ => {}
+        })
+    };
+
+    let on_backdrop_click = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+
+    html! {
+        <div class="palette-backdrop" onclick={ on_backdrop_click }>
+            <div class="search-palette" onclick={ Callback::from(|e: MouseEvent| e.stop_propagation()) }>
+                <input
+                    ref={ input_ref }
+                    type="search"
+                    class="palette-input"
+                    placeholder="Search systems, terms and connectives…"
+                    value={ (*query).clone() }
+                    oninput={ on_input }
+                    onkeydown={ on_keydown }
+                />
+                if results.is_empty() {
+                    <p class="palette-empty">{"No matches"}</p>
+                } else {
+                    <ul class="palette-results">
+                        { for results.iter().enumerate().map(|(i, item)| {
+                            let onclick = {
+                                let choose = choose.clone();
+                                let item = item.clone();
+                                Callback::from(move |_| choose(&item))
+                            };
+                            let onmouseenter = {
+                                let active = active.clone();
+                                Callback::from(move |_| active.set(i))
+                            };
+
+                            html! {
+                                <li
+                                    class={ classes!("palette-result", (i == active_index).then_some("active")) }
+                                    onclick={ onclick }
+                                    onmouseenter={ onmouseenter }
+                                >
+                                    <span class="palette-kind">{ item.kind.label() }</span>
+                                    <span class="palette-text">{ &item.text }</span>
+                                    <span class="palette-detail">{ &item.detail }</span>
+                                </li>
+                            }
+                        })}
+                    </ul>
+                }
+            </div>
+        </div>
+    }
+}
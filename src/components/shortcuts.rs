@@ -0,0 +1,97 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent};
+use yew::prelude::*;
+use crate::core::system_kind::SystemKind;
+
+/// App-wide keyboard shortcuts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shortcut {
+    SelectSystem(SystemKind),
+    ToggleEdgeLabels,
+    NavigateBack,
+    Search,
+    ToggleHelp,
+    Close,
+}
+
+/// Keys and what they do, as listed in the help overlay
+const SHORTCUTS: &[(&str, &str)] = &[
+    ("1 – 9", "Monad to Ennead"),
+    ("0  -  =", "Decad, Undecad, Dodecad"),
+    ("← →  ↑ ↓", "Previous / next node in position order"),
+    ("Tab", "Move between nodes and edges"),
+    ("Enter", "Select the focused node or edge"),
+    ("Esc", "Clear the selection or close an overlay"),
+    ("L", "Toggle edge labels"),
+    ("Backspace", "Back to the previous system"),
+    ("/  or  Ctrl K", "Search"),
+    ("?", "Show this list"),
+];
+
+/// Whether a key press is aimed at a text field or dropdown, and so isn't a shortcut
+pub fn is_typing(e: &KeyboardEvent) -> bool {
+    let Some(element) = e.target().and_then(|t| t.dyn_into::<Element>().ok()) else {
+        return false;
+    };
+    matches!(element.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA")
+}
+
+/// The app-wide shortcut for a key press, if any
+pub fn shortcut_for(e: &KeyboardEvent) -> Option<Shortcut> {
+    if e.alt_key() || is_typing(e) {
+        return None;
+    }
+    if e.ctrl_key() || e.meta_key() {
+        return (e.key().eq_ignore_ascii_case("k")).then_some(Shortcut::Search);
+    }
+
+    let order = match e.key().as_str() {
+        "0" => Some(10),
+        "-" => Some(11),
+        "=" => Some(12),
+        key => key.parse::<usize>().ok().filter(|n| (1..=9).contains(n)),
+    };
+    if let Some(kind) = order.and_then(SystemKind::from_order) {
+        return Some(Shortcut::SelectSystem(kind));
+    }
+
+    match e.key().as_str() {
+        "l" | "L" => Some(Shortcut::ToggleEdgeLabels),
+        "Backspace" => Some(Shortcut::NavigateBack),
+        "/" => Some(Shortcut::Search),
+        "?" => Some(Shortcut::ToggleHelp),
+        "Escape" => Some(Shortcut::Close),
+        _ => None,
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ShortcutHelpProps {
+    pub on_close: Callback<()>,
+}
+
+/// Overlay listing the keyboard shortcuts
+#[function_component(ShortcutHelp)]
+pub fn shortcut_help(props: &ShortcutHelpProps) -> Html {
+    let on_close = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+
+    html! {
+        <div class="palette-backdrop" onclick={ on_close.clone() }>
+            <div class="shortcut-help" onclick={ Callback::from(|e: MouseEvent| e.stop_propagation()) }>
+                <h3 class="legend-title">{"Keyboard shortcuts"}</h3>
+                <dl>
+                    { for SHORTCUTS.iter().map(|(keys, action)| html! {
+                        <div class="shortcut-row">
+                            <dt><kbd>{ *keys }</kbd></dt>
+                            <dd>{ *action }</dd>
+                        </div>
+                    })}
+                </dl>
+                <button class="shortcut-close" onclick={ on_close }>{"Close"}</button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,134 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::api::models::Slice;
+use crate::core::system_kind::SystemKind;
+
+/// Where the entries of a slice came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceSource {
+    /// The backend's `slice` query
+    Query,
+    /// Cut from the cached `allSystems` data
+    Cache,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SliceBrowserProps {
+    /// Position (1-based) being followed, if one has been chosen
+    #[prop_or_default]
+    pub position: Option<i32>,
+    /// Entries for the position, one per order, with where they came from
+    #[prop_or_default]
+    pub slice: Option<(Vec<Slice>, SliceSource)>,
+    #[prop_or_default]
+    pub error: Option<String>,
+    pub on_select_position: Callback<i32>,
+    pub on_navigate: Callback<SystemKind>,
+}
+
+/// Follows one position through every system from order p up to the Dodecad
+#[function_component(SliceBrowser)]
+pub fn slice_browser(props: &SliceBrowserProps) -> Html {
+    let max_position = SystemKind::DODECAD.order() as i32;
+
+    let on_position_change = {
+        let on_select_position = props.on_select_position.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(position) = select.value().parse::<i32>() {
+                on_select_position.emit(position);
+            }
+        })
+    };
+
+    html! {
+        <section class="legend slice-browser">
+            <h3 class="legend-title">{"Slice"}</h3>
+            <div class="slice-controls">
+                <label class="layout-select">
+                    {"Position"}
+                    <select onchange={ on_position_change }>
+                        <option value="" selected={ props.position.is_none() } disabled=true>{"—"}</option>
+                        { for (1..=max_position).map(|p| html! {
+                            <option value={ p.to_string() } selected={ props.position == Some(p) }>
+                                { p }
+                            </option>
+                        })}
+                    </select>
+                </label>
+                if let Some((_, source)) = props.slice {
+                    <span class="slice-source">
+                        { match source {
+                            SliceSource::Query => "from the slice query",
+                            SliceSource::Cache => "from cached systems",
+                        }}
+                    </span>
+                }
+            </div>
+            if let Some(ref error) = props.error {
+                <p class="slice-error">{ error }</p>
+            } else if let Some((ref entries, _)) = props.slice {
+                { render_entries(entries, &props.on_navigate) }
+            } else if props.position.is_some() {
+                <p class="slice-loading">{"Loading slice..."}</p>
+            }
+        </section>
+    }
+}
+
+fn render_entries(entries: &[Slice], on_navigate: &Callback<SystemKind>) -> Html {
+    if entries.is_empty() {
+        return html! { <p class="slice-error">{"No systems have this position"}</p> };
+    }
+
+    html! {
+        <table class="slice-table">
+            <thead>
+                <tr>
+                    <th>{"System"}</th>
+                    <th>{"Term"}</th>
+                    <th title="Position in the 800×800 view">{"Coordinate"}</th>
+                    <th>{"Colour"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                { for entries.iter().map(|entry| {
+                    let kind = SystemKind::from_order(entry.order.max(0) as usize);
+                    let onclick = {
+                        let on_navigate = on_navigate.clone();
+                        Callback::from(move |_| {
+                            if let Some(kind) = kind {
+                                on_navigate.emit(kind);
+                            }
+                        })
+                    };
+                    let term = entry.term.as_ref()
+                        .and_then(|t| t.character.as_ref())
+                        .map(|c| c.value.clone())
+                        .unwrap_or_default();
+
+                    html! {
+                        <tr class="slice-row" onclick={ onclick }>
+                            <td>
+                                { kind.map(|k| k.display_name()).unwrap_or_else(|| "Unknown".to_string()) }
+                                <span class="k-notation">{ format!("K{}", entry.order) }</span>
+                            </td>
+                            <td>{ term }</td>
+                            <td class="slice-coordinate">
+                                { entry.coordinate.as_ref()
+                                    .map(|c| format!("({:.2}, {:.2}, {:.2})", c.x, c.y, c.z))
+                                    .unwrap_or_default() }
+                            </td>
+                            <td>
+                                if let Some(ref colour) = entry.colour {
+                                    <span class="legend-swatch" style={ format!("background: {}", colour.value) }></span>
+                                    { &colour.value }
+                                }
+                            </td>
+                        </tr>
+                    }
+                })}
+            </tbody>
+        </table>
+    }
+}
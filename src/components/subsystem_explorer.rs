@@ -0,0 +1,152 @@
+use std::rc::Rc;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::core::subsystems::{binomial, combination_at};
+use crate::core::system_kind::SystemKind;
+
+/// Subsets shown per page
+const PAGE_SIZE: usize = 50;
+
+#[derive(Properties, PartialEq)]
+pub struct SubsystemExplorerProps {
+    pub system: Rc<SystemView>,
+    /// Positions (1-based) of the pinned subset, if any
+    #[prop_or_default]
+    pub selected: Option<Vec<i32>>,
+    /// Emitted with the hovered subset, or `None` when the pointer leaves it
+    pub on_hover: Callback<Option<Vec<i32>>>,
+    /// Emitted when a subset is clicked; clicking the pinned subset again emits `None`
+    pub on_select: Callback<Option<Vec<i32>>>,
+    #[prop_or_default]
+    pub on_navigate: Option<Callback<SystemKind>>,
+}
+
+/// Lists every Km inside the current Kn, one m-subset of terms per row
+#[function_component(SubsystemExplorer)]
+pub fn subsystem_explorer(props: &SubsystemExplorerProps) -> Html {
+    let system = &props.system;
+    let n = system.node_count();
+    let size = use_state(|| 3.min(n.saturating_sub(1)).max(2));
+    let page = use_state(|| 0usize);
+
+    if n < 3 {
+        return html! {};
+    }
+
+    let m = *size;
+    // Past what a usize can count there are too many subsets to page through
+    let total = binomial(n, m);
+    let listed = total.unwrap_or(0);
+    let page_count = listed.div_ceil(PAGE_SIZE).max(1);
+    let first = *page * PAGE_SIZE;
+
+    let on_size_change = {
+        let size = size.clone();
+        let page = page.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(m) = select.value().parse::<usize>() {
+                size.set(m);
+                page.set(0);
+            }
+        })
+    };
+
+    let on_prev = {
+        let page = page.clone();
+        Callback::from(move |_| page.set(page.saturating_sub(1)))
+    };
+    let on_next = {
+        let page = page.clone();
+        Callback::from(move |_| page.set((*page + 1).min(page_count - 1)))
+    };
+
+    let target = SystemKind::from_order(m);
+
+    html! {
+        <section class="legend subsystem-explorer">
+            <h3 class="legend-title">{"Sub-systems"}</h3>
+            <div class="subsystem-controls">
+                <label class="layout-select">
+                    {"Size"}
+                    <select onchange={ on_size_change }>
+                        { for (2..n).map(|k| html! {
+                            <option value={ k.to_string() } selected={ k == m }>
+                                { format!("K{}", k) }
+                            </option>
+                        })}
+                    </select>
+                </label>
+                <span class="subsystem-count">
+                    { match total {
+                        Some(total) => format!("{} × K{} in {}", total, m, system.k_notation()),
+                        None => format!("Too many K{} in {} to list", m, system.k_notation()),
+                    } }
+                </span>
+                if let (Some(on_navigate), Some(kind)) = (props.on_navigate.clone(), target) {
+                    <button
+                        class="subsystem-open"
+                        onclick={ Callback::from(move |_| on_navigate.emit(kind)) }
+                    >
+                        { format!("Open {} →", kind.display_name()) }
+                    </button>
+                }
+            </div>
+            <ul class="subsystem-list">
+                { for (first..(first + PAGE_SIZE).min(listed)).filter_map(|index| {
+                    let subset: Vec<i32> = combination_at(n, m, index)?
+                        .into_iter()
+                        .map(|i| i as i32 + 1)
+                        .collect();
+
+                    let is_selected = props.selected.as_ref() == Some(&subset);
+                    let label = subset.iter()
+                        .map(|&p| system.term_at(p).map(str::to_string).unwrap_or_else(|| p.to_string()))
+                        .collect::<Vec<_>>()
+                        .join(" · ");
+                    let positions = subset.iter()
+                        .map(|p| p.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",");
+
+                    let onmouseenter = {
+                        let on_hover = props.on_hover.clone();
+                        let subset = subset.clone();
+                        Callback::from(move |_| on_hover.emit(Some(subset.clone())))
+                    };
+                    let onmouseleave = {
+                        let on_hover = props.on_hover.clone();
+                        Callback::from(move |_| on_hover.emit(None))
+                    };
+                    let onclick = {
+                        let on_select = props.on_select.clone();
+                        let subset = subset.clone();
+                        Callback::from(move |_| {
+                            on_select.emit(if is_selected { None } else { Some(subset.clone()) })
+                        })
+                    };
+
+                    Some(html! {
+                        <li
+                            class={ if is_selected { "subsystem-item selected" } else { "subsystem-item" } }
+                            { onmouseenter }
+                            { onmouseleave }
+                            { onclick }
+                        >
+                            <span class="legend-key">{ positions }</span>
+                            <span class="legend-value">{ label }</span>
+                        </li>
+                    })
+                })}
+            </ul>
+            if page_count > 1 {
+                <div class="subsystem-pager">
+                    <button onclick={ on_prev } disabled={ *page == 0 }>{"‹"}</button>
+                    <span>{ format!("{} / {}", *page + 1, page_count) }</span>
+                    <button onclick={ on_next } disabled={ *page + 1 >= page_count }>{"›"}</button>
+                </div>
+            }
+        </section>
+    }
+}
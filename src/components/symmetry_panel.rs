@@ -0,0 +1,146 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use crate::api::models::ConnectiveChange;
+use crate::core::symmetry::{DihedralAction, Permutation};
+
+/// The outcome of the last symmetry action, for reporting
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetryReport {
+    pub action: String,
+    pub changes: Vec<ConnectiveChange>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SymmetryPanelProps {
+    pub node_count: usize,
+    /// Current term-to-position mapping
+    pub current: Permutation,
+    #[prop_or_default]
+    pub report: Option<SymmetryReport>,
+    /// Emitted with a label and the permutation to apply on top of the current one
+    pub on_apply: Callback<(String, Permutation)>,
+    pub on_reset: Callback<()>,
+}
+
+/// Controls for applying Dn actions and arbitrary permutations to the terms,
+/// with a report of which connective labels each action preserves
+#[function_component(SymmetryPanel)]
+pub fn symmetry_panel(props: &SymmetryPanelProps) -> Html {
+    let n = props.node_count;
+    let actions = DihedralAction::all(n);
+    let selected_action = use_state(|| 1usize.min(actions.len().saturating_sub(1)));
+    let permutation_text = use_state(String::new);
+    let parse_error = use_state(|| None::<String>);
+
+    if n < 2 {
+        return html! {};
+    }
+
+    let apply = |action: DihedralAction| {
+        let on_apply = props.on_apply.clone();
+        Callback::from(move |_| on_apply.emit((action.label(n), action.permutation(n))))
+    };
+
+    let on_action_change = {
+        let selected_action = selected_action.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(index) = select.value().parse::<usize>() {
+                selected_action.set(index);
+            }
+        })
+    };
+
+    let on_permutation_input = {
+        let permutation_text = permutation_text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            permutation_text.set(input.value());
+        })
+    };
+
+    let on_permutation_apply = {
+        let on_apply = props.on_apply.clone();
+        let permutation_text = permutation_text.clone();
+        let parse_error = parse_error.clone();
+        Callback::from(move |_| {
+            match Permutation::parse(&permutation_text, n) {
+                Ok(permutation) => {
+                    parse_error.set(None);
+                    on_apply.emit((format!("Permute {}", permutation), permutation));
+                }
+                Err(e) => parse_error.set(Some(e.to_string())),
+            }
+        })
+    };
+
+    let on_reset = {
+        let on_reset = props.on_reset.clone();
+        Callback::from(move |_| on_reset.emit(()))
+    };
+
+    let chosen = actions.get(*selected_action).copied().unwrap_or(DihedralAction::Rotation(0));
+
+    html! {
+        <section class="legend symmetry-panel">
+            <h3 class="legend-title">{ format!("Symmetry (D{})", n) }</h3>
+            <div class="symmetry-controls">
+                <button onclick={ apply(DihedralAction::Rotation(1)) }>{"↻ Rotate"}</button>
+                <button onclick={ apply(DihedralAction::Rotation(n - 1)) }>{"↺ Rotate"}</button>
+                <button onclick={ apply(DihedralAction::Reflection(0)) }>{"⇋ Reflect"}</button>
+                <select onchange={ on_action_change }>
+                    { for actions.iter().enumerate().map(|(index, action)| html! {
+                        <option value={ index.to_string() } selected={ index == *selected_action }>
+                            { action.label(n) }
+                        </option>
+                    })}
+                </select>
+                <button onclick={ apply(chosen) }>{"Apply"}</button>
+            </div>
+            <div class="symmetry-controls">
+                <input
+                    type="text"
+                    placeholder="(1 2 3) or 2 3 1 …"
+                    value={ (*permutation_text).clone() }
+                    oninput={ on_permutation_input }
+                />
+                <button onclick={ on_permutation_apply }>{"Permute"}</button>
+                <button onclick={ on_reset } disabled={ props.current.is_identity() }>{"Reset"}</button>
+            </div>
+            if let Some(ref error) = *parse_error {
+                <p class="symmetry-error">{ error }</p>
+            }
+            <p class="symmetry-current">
+                {"Current mapping: "}<code>{ props.current.to_string() }</code>
+            </p>
+            if let Some(ref report) = props.report {
+                { render_report(report) }
+            }
+        </section>
+    }
+}
+
+fn render_report(report: &SymmetryReport) -> Html {
+    let preserved = report.changes.iter().filter(|c| c.is_preserved()).count();
+
+    html! {
+        <div class="symmetry-report">
+            <p>
+                <strong>{ &report.action }</strong>
+                { format!(": {} of {} connectives preserved", preserved, report.changes.len()) }
+            </p>
+            <ul>
+                { for report.changes.iter().map(|change| html! {
+                    <li class={ if change.is_preserved() { "preserved" } else { "changed" } }>
+                        <span class="legend-key">{ format!("{} → {}", change.base_term, change.target_term) }</span>
+                        if change.is_preserved() {
+                            <span class="legend-value">{ &change.before }</span>
+                        } else {
+                            <span class="legend-value">{ format!("{} ⟶ {}", change.before, change.after) }</span>
+                        }
+                    </li>
+                })}
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::components::api_graph_view::ApiGraphView;
+use crate::core::system_kind::SystemKind;
+use crate::core::theme::GraphTheme;
+
+#[derive(Properties, PartialEq)]
+pub struct SystemComparisonProps {
+    pub systems: Vec<SystemView>,
+    /// System for the left panel; the right panel starts on the next order up
+    pub initial: SystemKind,
+    #[prop_or_default]
+    pub show_edge_labels: bool,
+    #[prop_or_default]
+    pub theme: GraphTheme,
+    /// Whether the left panel answers the arrow keys. Turn it off while something else,
+    /// like a dialog, owns the keyboard
+    #[prop_or(true)]
+    pub keyboard_navigation: bool,
+}
+
+/// Two systems in adjacent panels with a shared zoom and a diff of their characters
+#[function_component(SystemComparison)]
+pub fn system_comparison(props: &SystemComparisonProps) -> Html {
+    let left = use_state(|| props.initial);
+    let right = {
+        let systems = props.systems.clone();
+        let initial = props.initial;
+        use_state(move || {
+            systems.iter()
+                .filter_map(|s| s.kind())
+                .find(|kind| *kind > initial)
+                .unwrap_or(initial)
+        })
+    };
+    let zoom = use_state(|| 1.0f64);
+    let linked = use_state(|| true);
+    let linked_node = use_state(|| None::<usize>);
+
+    let find = |kind: SystemKind| props.systems.iter().find(|s| s.kind() == Some(kind));
+
+    let on_zoom_input = {
+        let zoom = zoom.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<f64>() {
+                zoom.set(value);
+            }
+        })
+    };
+
+    let on_linked_change = {
+        let linked = linked.clone();
+        let linked_node = linked_node.clone();
+        Callback::from(move |_| {
+            linked.set(!*linked);
+            linked_node.set(None);
+        })
+    };
+
+    let system_select = |side: UseStateHandle<SystemKind>| {
+        let current = *side;
+        let onchange = Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(kind) = select.value().parse::<SystemKind>() {
+                side.set(kind);
+            }
+        });
+
+        html! {
+            <select class="comparison-select" onchange={ onchange }>
+                { for props.systems.iter().filter_map(|s| s.kind()).map(|kind| html! {
+                    <option value={ kind.key() } selected={ kind == current }>
+                        { format!("{} ({})", kind.display_name(), kind.k_notation()) }
+                    </option>
+                })}
+            </select>
+        }
+    };
+
+    // Only the left panel answers the arrow keys; with linked selection the right follows
+    let panel = |side: UseStateHandle<SystemKind>, keyboard_navigation: bool| {
+        let kind = *side;
+        let on_node_select = {
+            let linked_node = linked_node.clone();
+            Callback::from(move |node: Option<usize>| linked_node.set(node))
+        };
+
+        html! {
+            <div class="comparison-panel">
+                { system_select(side) }
+                if let Some(system) = find(kind) {
+                    <ApiGraphView
+                        system={ system.clone() }
+                        show_edge_labels={ props.show_edge_labels }
+                        zoom={ *zoom }
+                        selected_node={ if *linked { *linked_node } else { None } }
+                        on_node_select={ (*linked).then_some(on_node_select) }
+                        keyboard_navigation={ keyboard_navigation }
+                        theme={ props.theme }
+                    />
+                } else {
+                    <div class="loading">{ format!("{} hasn't loaded", kind.display_name()) }</div>
+                }
+            </div>
+        }
+    };
+
+    html! {
+        <section class="system-comparison">
+            <div class="comparison-controls">
+                <label class="layout-select">
+                    {"Zoom"}
+                    <input
+                        type="range"
+                        min="0.5"
+                        max="3"
+                        step="0.1"
+                        value={ zoom.to_string() }
+                        oninput={ on_zoom_input }
+                    />
+                    <span>{ format!("{:.1}×", *zoom) }</span>
+                </label>
+                <label class="overlay-toggle">
+                    <input type="checkbox" checked={ *linked } onchange={ on_linked_change } />
+                    {"Link selection"}
+                </label>
+            </div>
+            if let (Some(a), Some(b)) = (find(*left), find(*right)) {
+                { render_diff(a, b) }
+            }
+            <div class="comparison-panels">
+                { panel(left.clone(), props.keyboard_navigation) }
+                { panel(right.clone(), false) }
+            </div>
+        </section>
+    }
+}
+
+/// Which term and connective characters the two systems share, and which are new to the second
+fn render_diff(left: &SystemView, right: &SystemView) -> Html {
+    let section = |title: &str, before: BTreeSet<&str>, after: BTreeSet<&str>| {
+        let shared: Vec<&str> = before.intersection(&after).copied().collect();
+        let added: Vec<&str> = after.difference(&before).copied().collect();
+        let dropped: Vec<&str> = before.difference(&after).copied().collect();
+
+        let list = |class: &'static str, label: String, values: Vec<&str>| html! {
+            <div class={ classes!("diff-group", class) }>
+                <span class="legend-key">{ label }</span>
+                <span class="legend-value">
+                    if values.is_empty() { {"—"} } else { { values.join(", ") } }
+                </span>
+            </div>
+        };
+
+        html! {
+            <div class="diff-section">
+                <h4>{ title }</h4>
+                { list("shared", format!("In both ({})", shared.len()), shared) }
+                { list("added", format!("New in {} ({})", right.display_name(), added.len()), added) }
+                { list("dropped", format!("Only in {} ({})", left.display_name(), dropped.len()), dropped) }
+            </div>
+        }
+    };
+
+    html! {
+        <div class="legend comparison-diff">
+            <h3 class="legend-title">{ format!("{} → {}", left.display_name(), right.display_name()) }</h3>
+            { section(&left.term_legend_title(), left.term_values(), right.term_values()) }
+            { section(&left.connective_legend_title(), left.connective_values(), right.connective_values()) }
+        </div>
+    }
+}
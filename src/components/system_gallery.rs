@@ -0,0 +1,188 @@
+use std::collections::BTreeSet;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::api::models::SystemView;
+use crate::core::geometry::GeometryCalculator;
+use crate::core::system_kind::SystemKind;
+use crate::core::theme::GraphTheme;
+
+/// A character picked out across every thumbnail
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Highlight {
+    Term(String),
+    Connective(String),
+}
+
+impl Highlight {
+    /// Option value for the highlight picker
+    fn key(&self) -> String {
+        match self {
+            Highlight::Term(value) => format!("term:{}", value),
+            Highlight::Connective(value) => format!("connective:{}", value),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Highlight> {
+        if let Some(value) = key.strip_prefix("term:") {
+            Some(Highlight::Term(value.to_string()))
+        } else {
+            key.strip_prefix("connective:").map(|value| Highlight::Connective(value.to_string()))
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SystemGalleryProps {
+    pub systems: Vec<SystemView>,
+    pub on_open: Callback<SystemKind>,
+    #[prop_or_default]
+    pub theme: GraphTheme,
+}
+
+/// Every loaded system as a thumbnail, for comparing them at a glance
+#[function_component(SystemGallery)]
+pub fn system_gallery(props: &SystemGalleryProps) -> Html {
+    let highlight = use_state(|| None::<Highlight>);
+
+    let terms: BTreeSet<&str> = props.systems.iter()
+        .flat_map(|system| system.terms.iter())
+        .filter_map(|term| term.character.as_ref())
+        .map(|c| c.value.as_str())
+        .collect();
+    let connectives: BTreeSet<&str> = props.systems.iter()
+        .flat_map(|system| system.connectives.iter())
+        .filter_map(|conn| conn.character.as_ref())
+        .map(|c| c.value.as_str())
+        .collect();
+
+    let on_highlight_change = {
+        let highlight = highlight.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            highlight.set(Highlight::from_key(&select.value()));
+        })
+    };
+    let selected_key = highlight.as_ref().map(Highlight::key).unwrap_or_default();
+
+    html! {
+        <section class="system-gallery">
+            <div class="gallery-controls">
+                <label class="layout-select">
+                    {"Highlight"}
+                    <select onchange={ on_highlight_change }>
+                        <option value="" selected={ highlight.is_none() }>{"None"}</option>
+                        <optgroup label="Terms">
+                            { for terms.iter().map(|value| {
+                                let key = Highlight::Term(value.to_string()).key();
+                                html! { <option value={ key.clone() } selected={ key == selected_key }>{ value }</option> }
+                            })}
+                        </optgroup>
+                        <optgroup label="Connectives">
+                            { for connectives.iter().map(|value| {
+                                let key = Highlight::Connective(value.to_string()).key();
+                                html! { <option value={ key.clone() } selected={ key == selected_key }>{ value }</option> }
+                            })}
+                        </optgroup>
+                    </select>
+                </label>
+            </div>
+            <div class="gallery-grid">
+                { for props.systems.iter().map(|system| render_thumbnail(system, highlight.as_ref(), &props.theme, &props.on_open)) }
+            </div>
+        </section>
+    }
+}
+
+fn render_thumbnail(
+    system: &SystemView,
+    highlight: Option<&Highlight>,
+    theme: &GraphTheme,
+    on_open: &Callback<SystemKind>,
+) -> Html {
+    let kind = system.kind();
+    let onclick = {
+        let on_open = on_open.clone();
+        Callback::from(move |_| {
+            if let Some(kind) = kind {
+                on_open.emit(kind);
+            }
+        })
+    };
+
+    let coverage = system.coverage();
+    let coverage_title = format!(
+        "Terms {}/{} · coordinates {}/{} · colours {}/{} · connectives {}/{}",
+        coverage.terms.0, coverage.terms.1,
+        coverage.coordinates.0, coverage.coordinates.1,
+        coverage.colours.0, coverage.colours.1,
+        coverage.connectives.0, coverage.connectives.1,
+    );
+    let percent = (coverage.fraction() * 100.0).round();
+
+    // Thumbnails are drawn small, so nodes are scaled up to stay visible
+    let node_radius = GeometryCalculator::node_radius_for(system.node_count()) * 2.0;
+    let matches = match highlight {
+        Some(Highlight::Term(value)) => system.terms.iter()
+            .any(|t| t.character.as_ref().is_some_and(|c| &c.value == value)),
+        Some(Highlight::Connective(value)) => system.connectives.iter()
+            .any(|c| c.character.as_ref().is_some_and(|c| &c.value == value)),
+        None => false,
+    };
+
+    let edges = system.lines.iter().filter_map(|line| {
+        let base = line.base_position?;
+        let target = line.target_position?;
+        let from = system.coordinate_at(base)?;
+        let to = system.coordinate_at(target)?;
+        let highlighted = matches!(highlight, Some(Highlight::Connective(value))
+            if system.connective_label(base, target).or_else(|| system.connective_label(target, base)) == Some(value.as_str()));
+
+        Some(html! {
+            <line
+                x1={ from.x.to_string() }
+                y1={ from.y.to_string() }
+                x2={ to.x.to_string() }
+                y2={ to.y.to_string() }
+                stroke={ if highlighted { theme.selected_edge } else { theme.edge } }
+                class={ classes!("thumbnail-edge", highlighted.then_some("highlighted")) }
+            />
+        })
+    }).collect::<Html>();
+
+    let nodes = system.coordinates.iter().map(|coord| {
+        let highlighted = matches!(highlight, Some(Highlight::Term(value))
+            if system.term_at(coord.position) == Some(value.as_str()));
+        let radius = if highlighted { node_radius * 1.6 } else { node_radius };
+
+        html! {
+            <circle
+                cx={ coord.x.to_string() }
+                cy={ coord.y.to_string() }
+                r={ radius.to_string() }
+                fill={ system.colour_at(coord.position).unwrap_or(theme.node).to_string() }
+                stroke={ if highlighted { theme.selected_node } else { theme.node_stroke } }
+                class={ classes!("thumbnail-node", highlighted.then_some("highlighted")) }
+            />
+        }
+    }).collect::<Html>();
+
+    html! {
+        <button
+            class={ classes!("gallery-item", (highlight.is_some() && !matches).then_some("dimmed")) }
+            onclick={ onclick }
+        >
+            <svg class="thumbnail-svg" viewBox="0 0 800 800" preserveAspectRatio="xMidYMid meet">
+                { edges }
+                { nodes }
+            </svg>
+            <div class="gallery-caption">
+                <span class="gallery-name">{ system.display_name() }</span>
+                <span class="k-notation">{ system.k_notation() }</span>
+            </div>
+            <div class="coverage" title={ coverage_title }>
+                <div class="coverage-bar" style={ format!("width: {}%", percent) }></div>
+                <span class="coverage-label">{ format!("{}% data", percent) }</span>
+            </div>
+        </button>
+    }
+}
@@ -0,0 +1,43 @@
+use yew::prelude::*;
+use crate::api::models::SystemView;
+
+#[derive(Properties, PartialEq)]
+pub struct SystemHeaderProps {
+    pub system: SystemView,
+}
+
+/// Information panel shown above the graph: name, K-notation, coherence,
+/// designations and live counts for the current system
+#[function_component(SystemHeader)]
+pub fn system_header(props: &SystemHeaderProps) -> Html {
+    let system = &props.system;
+
+    html! {
+        <header class="graph-info system-header">
+            <h2>{ system.display_name() }</h2>
+            <div class="k-notation">{ system.k_notation() }</div>
+            if let Some(ref coherence) = system.coherence {
+                <p class="description">{ coherence }</p>
+            }
+            <dl class="system-meta">
+                if let Some(ref designation) = system.term_designation {
+                    <div class="system-meta-item">
+                        <dt>{"Terms"}</dt>
+                        <dd>{ designation }</dd>
+                    </div>
+                }
+                if let Some(ref designation) = system.connective_designation {
+                    <div class="system-meta-item">
+                        <dt>{"Connectives"}</dt>
+                        <dd>{ designation }</dd>
+                    </div>
+                }
+            </dl>
+            <ul class="system-counts">
+                <li><strong>{ system.terms.len() }</strong>{" terms"}</li>
+                <li><strong>{ system.lines.len() }</strong>{" lines"}</li>
+                <li><strong>{ system.connectives.len() }</strong>{" connectives"}</li>
+            </ul>
+        </header>
+    }
+}
@@ -1,36 +1,134 @@
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
+use crate::api::models::Language;
+use crate::core::palette::{Palette, PaletteKind, PaletteMode};
 use crate::core::system_config::SystemConfig;
+use crate::core::system_kind::SystemKind;
+use crate::core::theme::ThemeKind;
 
 #[derive(Properties, PartialEq)]
 pub struct SystemSelectorProps {
     pub systems: Vec<SystemConfig>,
-    pub selected: String,
-    pub on_select: Callback<String>,
+    pub selected: SystemKind,
+    pub on_select: Callback<SystemKind>,
     #[prop_or_default]
     pub show_edge_labels: bool,
     #[prop_or_default]
     pub on_toggle_edge_labels: Option<Callback<()>>,
+    /// Whether the all-systems overview is open instead of a single system
+    #[prop_or_default]
+    pub show_gallery: bool,
+    #[prop_or_default]
+    pub on_show_gallery: Option<Callback<()>>,
+    /// Whether the side-by-side comparison is open
+    #[prop_or_default]
+    pub show_comparison: bool,
+    #[prop_or_default]
+    pub on_show_comparison: Option<Callback<()>>,
+    /// Whether the cross-system glossary is open
+    #[prop_or_default]
+    pub show_glossary: bool,
+    #[prop_or_default]
+    pub on_show_glossary: Option<Callback<()>>,
+    #[prop_or_default]
+    pub on_search: Option<Callback<()>>,
+    #[prop_or_default]
+    pub theme: ThemeKind,
+    #[prop_or_default]
+    pub on_select_theme: Option<Callback<ThemeKind>>,
+    #[prop_or_default]
+    pub palette: Palette,
+    #[prop_or_default]
+    pub on_select_palette: Option<Callback<Palette>>,
+    /// Languages the loaded colours come in; the choice is only offered when there's more than one
+    #[prop_or_default]
+    pub colour_languages: Vec<Language>,
+    #[prop_or(Language::Hex)]
+    pub colour_language: Language,
+    #[prop_or_default]
+    pub on_select_colour_language: Option<Callback<Language>>,
+}
+
+/// CSS variables carrying a system's own node and selection colours
+fn scheme_style(system: &SystemConfig) -> String {
+    format!(
+        "--system-colour: {}; --system-selected: {};",
+        system.color_scheme.nodes, system.color_scheme.selected_node,
+    )
 }
 
 #[function_component(SystemSelector)]
 pub fn system_selector(props: &SystemSelectorProps) -> Html {
+    let (primary, extended): (Vec<&SystemConfig>, Vec<&SystemConfig>) = props.systems
+        .iter()
+        .partition(|system| system.kind <= SystemKind::DODECAD);
+    let single_system = !props.show_gallery && !props.show_comparison && !props.show_glossary;
+    let extended_selected = single_system && props.selected > SystemKind::DODECAD;
+
+    let on_extended_change = {
+        let on_select = props.on_select.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(kind) = select.value().parse::<SystemKind>() {
+                on_select.emit(kind);
+            }
+        })
+    };
+
     html! {
         <nav class="top-nav">
             <div class="nav-items">
+                if let Some(ref on_show_gallery) = props.on_show_gallery {
+                    <button
+                        class={ if props.show_gallery { "nav-button selected" } else { "nav-button" } }
+                        onclick={{
+                            let on_show_gallery = on_show_gallery.clone();
+                            Callback::from(move |_| on_show_gallery.emit(()))
+                        }}
+                        title="All systems"
+                    >
+                        {"Overview"}
+                    </button>
+                }
+                if let Some(ref on_show_comparison) = props.on_show_comparison {
+                    <button
+                        class={ if props.show_comparison { "nav-button selected" } else { "nav-button" } }
+                        onclick={{
+                            let on_show_comparison = on_show_comparison.clone();
+                            Callback::from(move |_| on_show_comparison.emit(()))
+                        }}
+                        title="Compare two systems side by side"
+                    >
+                        {"Compare"}
+                    </button>
+                }
+                if let Some(ref on_show_glossary) = props.on_show_glossary {
+                    <button
+                        class={ if props.show_glossary { "nav-button selected" } else { "nav-button" } }
+                        onclick={{
+                            let on_show_glossary = on_show_glossary.clone();
+                            Callback::from(move |_| on_show_glossary.emit(()))
+                        }}
+                        title="Every character across the loaded systems"
+                    >
+                        {"Glossary"}
+                    </button>
+                }
                 {
-                    props.systems.iter().map(|system| {
-                        let is_selected = system.name == props.selected;
-                        let system_name = system.name.clone();
+                    primary.iter().map(|system| {
+                        let is_selected = single_system && system.kind == props.selected;
+                        let system_kind = system.kind;
                         let onclick = {
                             let on_select = props.on_select.clone();
                             Callback::from(move |_| {
-                                on_select.emit(system_name.clone());
+                                on_select.emit(system_kind);
                             })
                         };
 
                         html! {
                             <button
-                                class={ if is_selected { "nav-button selected" } else { "nav-button" } }
+                                class={ if is_selected { "nav-button system-button selected" } else { "nav-button system-button" } }
+                                style={ scheme_style(system) }
                                 onclick={ onclick }
                                 title={ system.k_notation.clone() }
                             >
@@ -39,8 +137,126 @@ pub fn system_selector(props: &SystemSelectorProps) -> Html {
                         }
                     }).collect::<Html>()
                 }
+
+                // Higher orders go in a dropdown so the bar stays one row
+                if !extended.is_empty() {
+                    <select
+                        class={ if extended_selected { "nav-select selected" } else { "nav-select" } }
+                        onchange={ on_extended_change }
+                    >
+                        <option value="" selected={ !extended_selected } disabled=true>
+                            { format!("K{}+", SystemKind::DODECAD.order() + 1) }
+                        </option>
+                        { for extended.iter().map(|system| html! {
+                            <option value={ system.kind.key() } selected={ extended_selected && system.kind == props.selected }>
+                                { format!("{} ({})", system.display_name, system.k_notation) }
+                            </option>
+                        })}
+                    </select>
+                }
             </div>
 
+            if let Some(ref on_search) = props.on_search {
+                <button
+                    class="nav-button search-button"
+                    onclick={{
+                        let on_search = on_search.clone();
+                        Callback::from(move |_| on_search.emit(()))
+                    }}
+                    title="Search terms, connectives and systems"
+                >
+                    {"Search"}
+                </button>
+            }
+
+            if let Some(ref on_select_theme) = props.on_select_theme {
+                <select
+                    class="nav-select theme-select"
+                    aria-label="Theme"
+                    onchange={{
+                        let on_select_theme = on_select_theme.clone();
+                        Callback::from(move |e: Event| {
+                            let select: HtmlSelectElement = e.target_unchecked_into();
+                            if let Some(theme) = ThemeKind::from_key(&select.value()) {
+                                on_select_theme.emit(theme);
+                            }
+                        })
+                    }}
+                >
+                    { for ThemeKind::ALL.iter().map(|theme| html! {
+                        <option value={ theme.key() } selected={ *theme == props.theme }>
+                            { theme.label() }
+                        </option>
+                    })}
+                </select>
+            }
+
+            if let (Some(on_select_colour_language), true) = (props.on_select_colour_language.as_ref(), props.colour_languages.len() > 1) {
+                <select
+                    class="nav-select colour-language-select"
+                    aria-label="Colour language"
+                    onchange={{
+                        let on_select_colour_language = on_select_colour_language.clone();
+                        Callback::from(move |e: Event| {
+                            let select: HtmlSelectElement = e.target_unchecked_into();
+                            if let Some(language) = Language::from_key(&select.value()) {
+                                on_select_colour_language.emit(language);
+                            }
+                        })
+                    }}
+                >
+                    { for props.colour_languages.iter().map(|language| html! {
+                        <option value={ language.key() } selected={ *language == props.colour_language }>
+                            { format!("{} colours", language.label()) }
+                        </option>
+                    })}
+                </select>
+            }
+
+            if let Some(ref on_select_palette) = props.on_select_palette {
+                <select
+                    class="nav-select palette-select"
+                    aria-label="Colour palette"
+                    onchange={{
+                        let on_select_palette = on_select_palette.clone();
+                        let palette = props.palette;
+                        Callback::from(move |e: Event| {
+                            let select: HtmlSelectElement = e.target_unchecked_into();
+                            if let Some(kind) = PaletteKind::from_key(&select.value()) {
+                                on_select_palette.emit(Palette { kind, ..palette });
+                            }
+                        })
+                    }}
+                >
+                    { for PaletteKind::ALL.iter().map(|kind| html! {
+                        <option value={ kind.key() } selected={ *kind == props.palette.kind }>
+                            { kind.label() }
+                        </option>
+                    })}
+                </select>
+                <select
+                    class="nav-select palette-mode-select"
+                    aria-label="How the palette treats API colours"
+                    disabled={ props.palette.kind == PaletteKind::Api }
+                    onchange={{
+                        let on_select_palette = on_select_palette.clone();
+                        let palette = props.palette;
+                        Callback::from(move |e: Event| {
+                            let select: HtmlSelectElement = e.target_unchecked_into();
+                            if let Some(mode) = PaletteMode::from_key(&select.value()) {
+                                on_select_palette.emit(Palette { mode, ..palette });
+                            }
+                        })
+                    }}
+                >
+                    { for PaletteMode::ALL.iter().map(|mode| html! {
+                        <option value={ mode.key() } selected={ *mode == props.palette.mode }>
+                            { mode.label() }
+                        </option>
+                    })}
+                </select>
+            }
+
             // Edge labels toggle switch
             if let Some(ref on_toggle) = props.on_toggle_edge_labels {
                 <div class="nav-controls">
@@ -0,0 +1,121 @@
+/// An sRGB colour parsed from CSS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Parse `#rgb`, `#rrggbb`, `rgb()`/`rgba()` or a handful of common names
+    pub fn parse(css: &str) -> Option<Rgb> {
+        let css = css.trim().to_ascii_lowercase();
+
+        if let Some(hex) = css.strip_prefix('#') {
+            let digits: Vec<u8> = hex.chars()
+                .map(|c| c.to_digit(16).map(|d| d as u8))
+                .collect::<Option<_>>()?;
+            return match digits.as_slice() {
+                [r, g, b] => Some(Rgb(r * 17, g * 17, b * 17)),
+                [r1, r2, g1, g2, b1, b2] => Some(Rgb(r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2)),
+                _ => None,
+            };
+        }
+
+        if let Some(args) = css.strip_prefix("rgba(").or_else(|| css.strip_prefix("rgb(")) {
+            let channels: Vec<u8> = args.trim_end_matches(')')
+                .split(',')
+                .take(3)
+                .map(|part| part.trim().parse::<f64>().ok().map(|v| v.clamp(0.0, 255.0) as u8))
+                .collect::<Option<_>>()?;
+            return match channels.as_slice() {
+                [r, g, b] => Some(Rgb(*r, *g, *b)),
+                _ => None,
+            };
+        }
+
+        match css.as_str() {
+            "black" => Some(Rgb(0, 0, 0)),
+            "white" => Some(Rgb(255, 255, 255)),
+            "red" => Some(Rgb(255, 0, 0)),
+            "green" => Some(Rgb(0, 128, 0)),
+            "blue" => Some(Rgb(0, 0, 255)),
+            "yellow" => Some(Rgb(255, 255, 0)),
+            "orange" => Some(Rgb(255, 165, 0)),
+            "purple" => Some(Rgb(128, 0, 128)),
+            "grey" | "gray" => Some(Rgb(128, 128, 128)),
+            _ => None,
+        }
+    }
+
+    /// WCAG relative luminance, from 0.0 (black) to 1.0 (white)
+    pub fn luminance(&self) -> f64 {
+        let linear = |channel: u8| {
+            let c = channel as f64 / 255.0;
+            if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    /// WCAG contrast ratio between two colours, from 1.0 to 21.0
+    pub fn contrast(&self, other: &Rgb) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+}
+
+/// Black or white, whichever reads better on `fill`, with the other as its outline.
+/// `None` if the fill can't be parsed.
+pub fn readable_text_on(fill: &str) -> Option<(&'static str, &'static str)> {
+    let fill = Rgb::parse(fill)?;
+    let black = Rgb(0, 0, 0);
+    let white = Rgb(255, 255, 255);
+
+    if fill.contrast(&black) >= fill.contrast(&white) {
+        Some(("#000000", "#ffffff"))
+    } else {
+        Some(("#ffffff", "#000000"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Rgb = Rgb(0, 0, 0);
+    const WHITE: Rgb = Rgb(255, 255, 255);
+
+    #[test]
+    fn parses_hex_rgb_and_names() {
+        assert_eq!(Rgb::parse("#FFB000"), Some(Rgb(255, 176, 0)));
+        assert_eq!(Rgb::parse(" #fb0 "), Some(Rgb(255, 187, 0)));
+        assert_eq!(Rgb::parse("rgb(12, 34, 56)"), Some(Rgb(12, 34, 56)));
+        assert_eq!(Rgb::parse("rgba(12, 34, 56, 0.5)"), Some(Rgb(12, 34, 56)));
+        assert_eq!(Rgb::parse("rgb(300, -4, 8.6)"), Some(Rgb(255, 0, 8)));
+        assert_eq!(Rgb::parse("Grey"), Some(Rgb(128, 128, 128)));
+    }
+
+    #[test]
+    fn rejects_malformed_colours() {
+        for bad in ["", "#", "#12", "#1234", "#12345", "#1234567", "#ggg", "#12345g", "123456", "rgb(1, 2)", "rgb(a, b, c)", "blurple"] {
+            assert_eq!(Rgb::parse(bad), None, "{:?} parsed", bad);
+        }
+        assert_eq!(readable_text_on("not a colour"), None);
+    }
+
+    #[test]
+    fn contrast_matches_wcag_references() {
+        assert!((BLACK.luminance() - 0.0).abs() < 1e-9);
+        assert!((WHITE.luminance() - 1.0).abs() < 1e-9);
+        assert!((BLACK.contrast(&WHITE) - 21.0).abs() < 1e-9);
+        assert!((WHITE.contrast(&BLACK) - 21.0).abs() < 1e-9);
+        assert!((WHITE.contrast(&WHITE) - 1.0).abs() < 1e-9);
+        // #767676 is the lightest grey that passes AA (4.5:1) on white
+        let grey = Rgb::parse("#767676").unwrap();
+        assert!((grey.contrast(&WHITE) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn text_takes_the_better_of_black_and_white() {
+        assert_eq!(readable_text_on("#000000"), Some(("#ffffff", "#000000")));
+        assert_eq!(readable_text_on("#FFFFFF"), Some(("#000000", "#ffffff")));
+        assert_eq!(readable_text_on("#F0E442"), Some(("#000000", "#ffffff")));
+        assert_eq!(readable_text_on("#0000CC"), Some(("#ffffff", "#000000")));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::core::geometry::Edge;
+
+/// How the edges of Kn are split into factors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecompositionKind {
+    /// K2m as 2m-1 perfect matchings (round-robin)
+    OneFactorization,
+    /// K(2m+1) as m Hamiltonian cycles (Walecki)
+    Hamiltonian,
+}
+
+impl DecompositionKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DecompositionKind::OneFactorization => "One-factorization",
+            DecompositionKind::Hamiltonian => "Hamiltonian decomposition",
+        }
+    }
+
+    /// Name for a single factor
+    pub fn factor_label(&self) -> &'static str {
+        match self {
+            DecompositionKind::OneFactorization => "Matching",
+            DecompositionKind::Hamiltonian => "Cycle",
+        }
+    }
+}
+
+/// A partition of the edges of Kn into edge-disjoint factors
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decomposition {
+    pub kind: DecompositionKind,
+    /// Each factor's edges, 0-based with `from < to`
+    pub factors: Vec<Vec<Edge>>,
+}
+
+impl Decomposition {
+    /// Decompose Kn: a one-factorization for even n, Hamiltonian cycles for odd n
+    pub fn for_order(node_count: usize) -> Option<Decomposition> {
+        match node_count {
+            0 | 1 => None,
+            n if n % 2 == 0 => Some(Self::round_robin(n)),
+            n => Some(Self::walecki(n)),
+        }
+    }
+
+    /// Index of the factor containing the edge between two nodes (0-based)
+    pub fn factor_of(&self, a: usize, b: usize) -> Option<usize> {
+        let (from, to) = (a.min(b), a.max(b));
+        self.factors.iter()
+            .position(|factor| factor.iter().any(|e| e.from == from && e.to == to))
+    }
+
+    /// Round-robin tournament: node n-1 is a fixed hub and nodes 0..n-1 rotate around
+    /// a ring of m = n-1 places. Each round pairs the hub with that round's node and
+    /// the rest off symmetrically around it.
+    fn round_robin(n: usize) -> Decomposition {
+        let m = n - 1;
+        let hub = n - 1;
+
+        let factors = (0..m)
+            .map(|round| {
+                let mut matching = vec![edge(round, hub)];
+                for k in 1..n / 2 {
+                    let a = (round + k) % m;
+                    let b = (round + m - k) % m;
+                    matching.push(edge(a, b));
+                }
+                matching
+            })
+            .collect();
+
+        Decomposition { kind: DecompositionKind::OneFactorization, factors }
+    }
+
+    /// Walecki's construction: node n-1 is the hub, the other 2m nodes sit on a
+    /// circle, and each cycle zig-zags across the circle before closing through the hub
+    fn walecki(n: usize) -> Decomposition {
+        let ring = n - 1;
+        let hub = n - 1;
+
+        let factors = (0..ring / 2)
+            .map(|start| {
+                let path: Vec<usize> = (0..ring)
+                    .map(|j| {
+                        if j % 2 == 1 {
+                            (start + j.div_ceil(2)) % ring
+                        } else {
+                            (start + ring - j / 2) % ring
+                        }
+                    })
+                    .collect();
+
+                let mut cycle = vec![edge(hub, path[0])];
+                cycle.extend(path.windows(2).map(|pair| edge(pair[0], pair[1])));
+                cycle.push(edge(path[ring - 1], hub));
+                cycle
+            })
+            .collect();
+
+        Decomposition { kind: DecompositionKind::Hamiltonian, factors }
+    }
+}
+
+fn edge(a: usize, b: usize) -> Edge {
+    Edge { from: a.min(b), to: a.max(b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Degree of every node in a factor
+    fn degrees(n: usize, factor: &[Edge]) -> Vec<usize> {
+        let mut degrees = vec![0; n];
+        for e in factor {
+            degrees[e.from] += 1;
+            degrees[e.to] += 1;
+        }
+        degrees
+    }
+
+    #[test]
+    fn factors_partition_kn() {
+        for n in 2..=12 {
+            let decomposition = Decomposition::for_order(n).unwrap();
+            let mut seen = HashSet::new();
+            for e in decomposition.factors.iter().flatten() {
+                assert!(e.from < e.to && e.to < n, "K{}: bad edge {:?}", n, e);
+                assert!(seen.insert((e.from, e.to)), "K{}: edge {:?} in two factors", n, e);
+            }
+            assert_eq!(seen.len(), n * (n - 1) / 2, "K{}: not every edge covered", n);
+        }
+    }
+
+    #[test]
+    fn even_orders_split_into_perfect_matchings() {
+        for n in (2..=12).step_by(2) {
+            let decomposition = Decomposition::for_order(n).unwrap();
+            assert_eq!(decomposition.kind, DecompositionKind::OneFactorization);
+            assert_eq!(decomposition.factors.len(), n - 1);
+            for factor in &decomposition.factors {
+                assert!(degrees(n, factor).iter().all(|&d| d == 1), "K{}: {:?} is not a perfect matching", n, factor);
+            }
+        }
+    }
+
+    #[test]
+    fn odd_orders_split_into_hamiltonian_cycles() {
+        for n in (3..=11).step_by(2) {
+            let decomposition = Decomposition::for_order(n).unwrap();
+            assert_eq!(decomposition.kind, DecompositionKind::Hamiltonian);
+            assert_eq!(decomposition.factors.len(), (n - 1) / 2);
+            for cycle in &decomposition.factors {
+                assert_eq!(cycle.len(), n);
+                assert!(degrees(n, cycle).iter().all(|&d| d == 2), "K{}: {:?} is not 2-regular", n, cycle);
+
+                // 2-regular and connected means a single cycle through every node
+                let mut reached = HashSet::from([0]);
+                let mut frontier = vec![0];
+                while let Some(node) = frontier.pop() {
+                    for e in cycle {
+                        let next = if e.from == node { e.to } else if e.to == node { e.from } else { continue };
+                        if reached.insert(next) {
+                            frontier.push(next);
+                        }
+                    }
+                }
+                assert_eq!(reached.len(), n, "K{}: {:?} is not connected", n, cycle);
+            }
+        }
+    }
+
+    #[test]
+    fn monad_has_no_decomposition() {
+        assert!(Decomposition::for_order(0).is_none());
+        assert!(Decomposition::for_order(1).is_none());
+    }
+}
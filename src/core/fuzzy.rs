@@ -0,0 +1,104 @@
+/// Score how well `query` matches `candidate` as a case-insensitive subsequence,
+/// or `None` if it doesn't match at all. Higher is better.
+///
+/// Consecutive runs and matches at the start of words score extra, gaps cost a
+/// little, and a plain substring match outranks any scattered one.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(fold_case)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = candidate.chars().map(fold_case).collect();
+    let mut score = 0i32;
+    let mut next = 0usize;
+    let mut previous: Option<usize> = None;
+
+    for &wanted in &query {
+        let found = (next..chars.len()).find(|&i| chars[i] == wanted)?;
+
+        score += 1;
+        match previous {
+            Some(p) if p + 1 == found => score += 5,
+            Some(p) => score -= (found - p - 1).min(5) as i32,
+            None => score -= found.min(5) as i32,
+        }
+        if found == 0 || !chars[found - 1].is_alphanumeric() {
+            score += 8;
+        }
+
+        previous = Some(found);
+        next = found + 1;
+    }
+
+    // The query lost its whitespace above, so compare against the candidate without it too
+    let haystack: String = chars.iter().filter(|c| !c.is_whitespace()).collect();
+    let needle: String = query.iter().collect();
+    if let Some(at) = haystack.find(&needle) {
+        score += if at == 0 { 30 } else { 20 };
+    }
+
+    // Prefer shorter candidates among equally good matches
+    Some(score - (chars.len() / 8) as i32)
+}
+
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "Tetrad"), Some(0));
+        assert_eq!(fuzzy_score("  ", "Tetrad"), Some(0));
+    }
+
+    #[test]
+    fn letters_must_appear_in_order() {
+        assert!(fuzzy_score("tda", "Tetrad").is_none());
+        assert!(fuzzy_score("x", "Tetrad").is_none());
+        assert!(fuzzy_score("ttr", "Tetrad").is_some());
+    }
+
+    #[test]
+    fn matching_ignores_case() {
+        assert_eq!(fuzzy_score("TETRAD", "tetrad"), fuzzy_score("tetrad", "Tetrad"));
+    }
+
+    #[test]
+    fn spaced_query_gets_the_substring_bonus() {
+        let spaced = fuzzy_score("free energy", "Free Energy").unwrap();
+        let scattered = fuzzy_score("free energy", "Free Range Energy").unwrap();
+        assert!(spaced > scattered, "{} <= {}", spaced, scattered);
+        assert_eq!(fuzzy_score("free energy", "Free Energy"), fuzzy_score("freeenergy", "Free Energy"));
+    }
+
+    #[test]
+    fn prefix_beats_substring_beats_scattered() {
+        let prefix = fuzzy_score("tri", "Triad").unwrap();
+        let substring = fuzzy_score("tri", "Matrix").unwrap();
+        let scattered = fuzzy_score("tri", "Tetrad Ring").unwrap();
+        assert!(prefix > substring, "{} <= {}", prefix, substring);
+        assert!(substring > scattered, "{} <= {}", substring, scattered);
+    }
+
+    #[test]
+    fn word_starts_beat_mid_word_matches() {
+        let initials = fuzzy_score("fe", "Free Energy").unwrap();
+        let mid_word = fuzzy_score("fe", "Reformed").unwrap();
+        assert!(initials > mid_word, "{} <= {}", initials, mid_word);
+    }
+
+    #[test]
+    fn shorter_candidates_win_ties() {
+        let short = fuzzy_score("dyad", "Dyad").unwrap();
+        let long = fuzzy_score("dyad", "Dyad of the extended long-named systems").unwrap();
+        assert!(short > long);
+    }
+}
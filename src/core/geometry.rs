@@ -1,4 +1,5 @@
 use std::f64::consts::PI;
+use crate::core::system_kind::SystemKind;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Point {
@@ -15,58 +16,427 @@ pub struct Edge {
 #[derive(Debug, Clone)]
 pub struct GraphLayout {
     pub nodes: Vec<Point>,
-    pub edges: Vec<Edge>,
-    pub node_radius: f64,
     pub symbolic_circle: Option<SymbolicCircle>,
     pub symbolic_circles: Vec<SymbolicCircle>,
 }
 
+/// Edges of Kn that share a step length around the polygon: step 1 is the polygon
+/// {n/1} itself, step k the star polygon {n/k} (a compound figure when k divides n)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordClass {
+    pub step: usize,
+    pub edges: Vec<Edge>,
+}
+
+impl ChordClass {
+    /// Schläfli symbol for this class, e.g. "{7/2}"
+    pub fn schlafli(&self, node_count: usize) -> String {
+        format!("{{{}/{}}}", node_count, self.step)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SymbolicCircle {
     pub center: Point,
     pub radius: f64,
 }
 
+/// Rotation, uniform scale and translation, possibly mirrored, taking one set of
+/// points onto another
+#[derive(Debug, Clone, PartialEq)]
+pub struct Similarity {
+    from_center: Point,
+    to_center: Point,
+    /// Rotation and scale as the complex number `cos + i sin`, times the scale
+    re: f64,
+    im: f64,
+    mirrored: bool,
+}
+
+impl Similarity {
+    /// Least-squares fit taking each `from` point to the `to` point at the same index.
+    /// `None` with fewer than two pairs or when the `from` points coincide. Two pairs
+    /// can't tell a mirror image apart, so they get the unmirrored fit.
+    pub fn fit(from: &[Point], to: &[Point]) -> Option<Similarity> {
+        if from.len() != to.len() || from.len() < 2 {
+            return None;
+        }
+        let from_center = GeometryCalculator::centroid(from)?;
+        let to_center = GeometryCalculator::centroid(to)?;
+
+        let spread: f64 = from.iter()
+            .map(|p| (p.x - from_center.x).powi(2) + (p.y - from_center.y).powi(2))
+            .sum();
+        if spread < f64::EPSILON {
+            return None;
+        }
+
+        // For each handedness the best rotation is sum(conj(a) * b) / sum(|a|^2)
+        let fit = |mirrored: bool| {
+            let (mut re, mut im) = (0.0, 0.0);
+            for (a, b) in from.iter().zip(to) {
+                let ax = a.x - from_center.x;
+                let ay = if mirrored { from_center.y - a.y } else { a.y - from_center.y };
+                let (bx, by) = (b.x - to_center.x, b.y - to_center.y);
+                re += ax * bx + ay * by;
+                im += ax * by - ay * bx;
+            }
+            Similarity {
+                from_center: from_center.clone(),
+                to_center: to_center.clone(),
+                re: re / spread,
+                im: im / spread,
+                mirrored,
+            }
+        };
+        let residual = |similarity: &Similarity| -> f64 {
+            from.iter().zip(to)
+                .map(|(a, b)| {
+                    let p = similarity.apply(a);
+                    (p.x - b.x).powi(2) + (p.y - b.y).powi(2)
+                })
+                .sum()
+        };
+
+        let direct = fit(false);
+        let mirrored = fit(true);
+        if residual(&mirrored) < residual(&direct) {
+            Some(mirrored)
+        } else {
+            Some(direct)
+        }
+    }
+
+    /// The move taking `from` to `to`, with no rotation or scaling
+    pub fn translation(from: &Point, to: &Point) -> Similarity {
+        Similarity {
+            from_center: from.clone(),
+            to_center: to.clone(),
+            re: 1.0,
+            im: 0.0,
+            mirrored: false,
+        }
+    }
+
+    pub fn apply(&self, point: &Point) -> Point {
+        let x = point.x - self.from_center.x;
+        let y = if self.mirrored { self.from_center.y - point.y } else { point.y - self.from_center.y };
+        Point {
+            x: self.to_center.x + self.re * x - self.im * y,
+            y: self.to_center.y + self.im * x + self.re * y,
+        }
+    }
+}
+
+/// Geometric overlays that can be drawn on top of a laid-out system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverlayKind {
+    SymbolicCircles,
+    EnclosingCircle,
+    Centroid,
+    InscribedPolygon,
+}
+
+impl OverlayKind {
+    pub const ALL: [OverlayKind; 4] = [
+        OverlayKind::SymbolicCircles,
+        OverlayKind::EnclosingCircle,
+        OverlayKind::Centroid,
+        OverlayKind::InscribedPolygon,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OverlayKind::SymbolicCircles => "Symbolic circles",
+            OverlayKind::EnclosingCircle => "Enclosing circle",
+            OverlayKind::Centroid => "Centroid",
+            OverlayKind::InscribedPolygon => "Inscribed polygon",
+        }
+    }
+}
+
+/// A strategy for positioning the nodes of a system in viewport space
+pub trait Layout {
+    /// Positions for `node_count` nodes around `center`, fitting within `size`.
+    /// `provided` holds positions supplied by the API, indexed by position - 1.
+    fn positions(&self, node_count: usize, provided: &[Option<Point>], center: &Point, size: f64) -> Vec<Point>;
+}
+
+/// Nodes evenly spaced on a circle, starting at the top
+pub struct RegularPolygonLayout;
+
+impl Layout for RegularPolygonLayout {
+    fn positions(&self, node_count: usize, _provided: &[Option<Point>], center: &Point, size: f64) -> Vec<Point> {
+        if node_count == 1 {
+            return vec![center.clone()];
+        }
+        ring(node_count, center, size * 0.40, -PI / 2.0)
+    }
+}
+
+/// The first term at the centre, the rest evenly spaced around it
+pub struct StarLayout;
+
+impl Layout for StarLayout {
+    fn positions(&self, node_count: usize, _provided: &[Option<Point>], center: &Point, size: f64) -> Vec<Point> {
+        if node_count == 0 {
+            return vec![];
+        }
+        let mut nodes = vec![center.clone()];
+        nodes.extend(ring(node_count - 1, center, size * 0.40, -PI / 2.0));
+        nodes
+    }
+}
+
+/// Nodes spread over concentric rings, outer rings holding more nodes
+pub struct ConcentricRingsLayout;
+
+impl Layout for ConcentricRingsLayout {
+    fn positions(&self, node_count: usize, _provided: &[Option<Point>], center: &Point, size: f64) -> Vec<Point> {
+        if node_count <= 1 {
+            return vec![center.clone(); node_count];
+        }
+        let ring_count = node_count.div_ceil(6);
+        let outer_radius = size * 0.42;
+
+        // Ring k (1-based from the centre) takes a share of nodes proportional to k
+        let total_weight: usize = (1..=ring_count).sum();
+        let mut remaining = node_count;
+        let mut nodes = Vec::with_capacity(node_count);
+
+        for k in (1..=ring_count).rev() {
+            let count = if k == 1 {
+                remaining
+            } else {
+                (node_count * k / total_weight).min(remaining)
+            };
+            remaining -= count;
+
+            let radius = outer_radius * k as f64 / ring_count as f64;
+            // Offset alternate rings by half a step so nodes don't line up radially
+            let rotation = -PI / 2.0 + if k % 2 == 0 { PI / count.max(1) as f64 } else { 0.0 };
+            nodes.extend(ring(count, center, radius, rotation));
+        }
+
+        nodes
+    }
+}
+
+/// Fruchterman–Reingold spring embedding, seeded from the API positions when available
+pub struct ForceDirectedLayout {
+    pub iterations: usize,
+}
+
+impl Default for ForceDirectedLayout {
+    fn default() -> Self {
+        Self { iterations: 200 }
+    }
+}
+
+impl Layout for ForceDirectedLayout {
+    fn positions(&self, node_count: usize, provided: &[Option<Point>], center: &Point, size: f64) -> Vec<Point> {
+        if node_count < 2 {
+            return vec![center.clone(); node_count];
+        }
+
+        // Seed from the API layout, nudged so coincident points can separate
+        let seed = ring(node_count, center, size * 0.30, -PI / 2.0);
+        let mut nodes: Vec<Point> = (0..node_count)
+            .map(|i| provided.get(i).cloned().flatten().unwrap_or_else(|| seed[i].clone()))
+            .collect();
+
+        let area = size * size * 0.6;
+        let k = (area / node_count as f64).sqrt();
+        let mut temperature = size * 0.1;
+        let cooling = temperature / self.iterations.max(1) as f64;
+        let edges = GeometryCalculator::generate_complete_graph_edges(node_count);
+
+        for _ in 0..self.iterations {
+            let mut displacement = vec![(0.0, 0.0); node_count];
+
+            for i in 0..node_count {
+                for j in 0..node_count {
+                    if i == j {
+                        continue;
+                    }
+                    let dx = nodes[i].x - nodes[j].x;
+                    let dy = nodes[i].y - nodes[j].y;
+                    let distance = (dx * dx + dy * dy).sqrt().max(0.01);
+                    let repulsion = k * k / distance;
+                    displacement[i].0 += dx / distance * repulsion;
+                    displacement[i].1 += dy / distance * repulsion;
+                }
+            }
+
+            for edge in &edges {
+                let dx = nodes[edge.from].x - nodes[edge.to].x;
+                let dy = nodes[edge.from].y - nodes[edge.to].y;
+                let distance = (dx * dx + dy * dy).sqrt().max(0.01);
+                let attraction = distance * distance / k;
+                displacement[edge.from].0 -= dx / distance * attraction;
+                displacement[edge.from].1 -= dy / distance * attraction;
+                displacement[edge.to].0 += dx / distance * attraction;
+                displacement[edge.to].1 += dy / distance * attraction;
+            }
+
+            for (node, (dx, dy)) in nodes.iter_mut().zip(displacement) {
+                let length = (dx * dx + dy * dy).sqrt().max(0.01);
+                node.x += dx / length * length.min(temperature);
+                node.y += dy / length * length.min(temperature);
+            }
+
+            temperature = (temperature - cooling).max(0.5);
+        }
+
+        fit_to_size(nodes, center, size * 0.42)
+    }
+}
+
+/// Positions exactly as the API returned them, falling back to a regular polygon for gaps
+pub struct ProvidedLayout;
+
+impl Layout for ProvidedLayout {
+    fn positions(&self, node_count: usize, provided: &[Option<Point>], center: &Point, size: f64) -> Vec<Point> {
+        let fallback = RegularPolygonLayout.positions(node_count, provided, center, size);
+        (0..node_count)
+            .map(|i| provided.get(i).cloned().flatten().unwrap_or_else(|| fallback[i].clone()))
+            .collect()
+    }
+}
+
+/// The layouts a user can choose between
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LayoutKind {
+    #[default]
+    AsProvided,
+    RegularPolygon,
+    Star,
+    ConcentricRings,
+    ForceDirected,
+}
+
+impl LayoutKind {
+    pub const ALL: [LayoutKind; 5] = [
+        LayoutKind::AsProvided,
+        LayoutKind::RegularPolygon,
+        LayoutKind::Star,
+        LayoutKind::ConcentricRings,
+        LayoutKind::ForceDirected,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LayoutKind::AsProvided => "As provided",
+            LayoutKind::RegularPolygon => "Regular polygon",
+            LayoutKind::Star => "Star",
+            LayoutKind::ConcentricRings => "Concentric rings",
+            LayoutKind::ForceDirected => "Force-directed",
+        }
+    }
+
+    /// Stable identifier, used for form values
+    pub fn key(&self) -> &'static str {
+        match self {
+            LayoutKind::AsProvided => "provided",
+            LayoutKind::RegularPolygon => "polygon",
+            LayoutKind::Star => "star",
+            LayoutKind::ConcentricRings => "rings",
+            LayoutKind::ForceDirected => "force",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<LayoutKind> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    /// Whether the nodes always sit around a regular polygon in position order, so the
+    /// step between two positions is the chord between them. The API's own coordinates
+    /// promise no such order (the tetrad is a diamond: top, right, left, bottom).
+    pub fn has_cyclic_order(&self) -> bool {
+        *self == LayoutKind::RegularPolygon
+    }
+
+    pub fn layout(&self) -> Box<dyn Layout> {
+        match self {
+            LayoutKind::AsProvided => Box::new(ProvidedLayout),
+            LayoutKind::RegularPolygon => Box::new(RegularPolygonLayout),
+            LayoutKind::Star => Box::new(StarLayout),
+            LayoutKind::ConcentricRings => Box::new(ConcentricRingsLayout),
+            LayoutKind::ForceDirected => Box::new(ForceDirectedLayout::default()),
+        }
+    }
+}
+
+/// `count` points evenly spaced on a circle, the first at angle `rotation`
+fn ring(count: usize, center: &Point, radius: f64, rotation: f64) -> Vec<Point> {
+    (0..count)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / count as f64 + rotation;
+            Point {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+/// Scale and centre points so the furthest lies `radius` from `center`
+fn fit_to_size(points: Vec<Point>, center: &Point, radius: f64) -> Vec<Point> {
+    let Some(centroid) = GeometryCalculator::centroid(&points) else {
+        return points;
+    };
+    let extent = points.iter()
+        .map(|p| ((p.x - centroid.x).powi(2) + (p.y - centroid.y).powi(2)).sqrt())
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+    let scale = radius / extent;
+
+    points.into_iter()
+        .map(|p| Point {
+            x: center.x + (p.x - centroid.x) * scale,
+            y: center.y + (p.y - centroid.y) * scale,
+        })
+        .collect()
+}
+
 pub struct GeometryCalculator;
 
 impl GeometryCalculator {
     pub fn calculate_system_layout(
-        system_type: &str,
+        system: SystemKind,
         center_x: f64,
         center_y: f64,
         size: f64,
     ) -> GraphLayout {
-        let node_count = Self::get_node_count(system_type);
-        let nodes = Self::calculate_node_positions(node_count, center_x, center_y, size);
-        let edges = Self::generate_complete_graph_edges(node_count);
-        let node_radius = 12.0; // Fixed node radius
-        let symbolic_circle = Self::get_symbolic_circle(system_type, center_x, center_y, size);
-        let symbolic_circles = Self::get_symbolic_circles(system_type, center_x, center_y, size);
+        let mut layout = Self::calculate_generic_layout(system.order(), center_x, center_y, size);
+        layout.symbolic_circle = Self::get_symbolic_circle(system, center_x, center_y, size);
+        layout.symbolic_circles = Self::get_symbolic_circles(system, center_x, center_y, size);
+        layout
+    }
 
+    /// Layout for a complete graph Kn, without any system-specific symbolic circles
+    pub fn calculate_generic_layout(
+        node_count: usize,
+        center_x: f64,
+        center_y: f64,
+        size: f64,
+    ) -> GraphLayout {
         GraphLayout {
-            nodes,
-            edges,
-            node_radius,
-            symbolic_circle,
-            symbolic_circles,
+            nodes: Self::calculate_node_positions(node_count, center_x, center_y, size),
+            symbolic_circle: None,
+            symbolic_circles: vec![],
         }
     }
 
-    fn get_node_count(system_type: &str) -> usize {
-        match system_type {
-            "monad" => 1,
-            "dyad" => 2,
-            "triad" => 3,
-            "tetrad" => 4,
-            "pentad" => 5,
-            "hexad" => 6,
-            "heptad" => 7,
-            "octad" => 8,
-            "ennead" => 9,
-            "decad" => 10,
-            "undecad" => 11,
-            "dodecad" => 12,
-            _ => 1,
+    /// Node radius for a system, shrinking past K24 so neighbouring nodes stay apart
+    pub fn node_radius_for(node_count: usize) -> f64 {
+        const BASE_RADIUS: f64 = 12.0;
+        const CROWDED_FROM: usize = 24;
+
+        if node_count <= CROWDED_FROM {
+            BASE_RADIUS
+        } else {
+            (BASE_RADIUS * CROWDED_FROM as f64 / node_count as f64).max(4.0)
         }
     }
 
@@ -143,9 +513,9 @@ impl GeometryCalculator {
         edges
     }
 
-    fn get_symbolic_circle(system_type: &str, center_x: f64, center_y: f64, size: f64) -> Option<SymbolicCircle> {
-        match system_type {
-            "monad" => {
+    fn get_symbolic_circle(system: SystemKind, center_x: f64, center_y: f64, size: f64) -> Option<SymbolicCircle> {
+        match system {
+            SystemKind::MONAD => {
                 Some(SymbolicCircle {
                     center: Point { x: center_x, y: center_y },
                     radius: size * 0.45,
@@ -155,9 +525,9 @@ impl GeometryCalculator {
         }
     }
 
-    fn get_symbolic_circles(system_type: &str, center_x: f64, center_y: f64, size: f64) -> Vec<SymbolicCircle> {
-        match system_type {
-            "dyad" => {
+    fn get_symbolic_circles(system: SystemKind, center_x: f64, center_y: f64, size: f64) -> Vec<SymbolicCircle> {
+        match system {
+            SystemKind::DYAD => {
                 let spacing = size * 0.18;
                 let radius = size * 0.36;
                 vec![
@@ -174,4 +544,190 @@ impl GeometryCalculator {
             _ => vec![],
         }
     }
+
+    /// Centroid of a set of node positions
+    pub fn centroid(points: &[Point]) -> Option<Point> {
+        if points.is_empty() {
+            return None;
+        }
+        let n = points.len() as f64;
+        Some(Point {
+            x: points.iter().map(|p| p.x).sum::<f64>() / n,
+            y: points.iter().map(|p| p.y).sum::<f64>() / n,
+        })
+    }
+
+    /// Circle centred on the centroid that passes through the furthest node.
+    /// For regular polygon layouts this is the circumcircle; for other point sets it
+    /// encloses every node but need not pass through more than one.
+    pub fn enclosing_circle(points: &[Point]) -> Option<SymbolicCircle> {
+        if points.len() < 2 {
+            return None;
+        }
+        let center = Self::centroid(points)?;
+        let radius = points.iter()
+            .map(|p| ((p.x - center.x).powi(2) + (p.y - center.y).powi(2)).sqrt())
+            .fold(0.0, f64::max);
+
+        Some(SymbolicCircle { center, radius })
+    }
+
+    /// Nodes ordered by angle around the centroid, forming the polygon inscribed in the enclosing circle
+    pub fn inscribed_polygon(points: &[Point]) -> Vec<Point> {
+        if points.len() < 3 {
+            return vec![];
+        }
+        let Some(center) = Self::centroid(points) else {
+            return vec![];
+        };
+
+        let mut ordered = points.to_vec();
+        ordered.sort_by(|a, b| {
+            let angle_a = (a.y - center.y).atan2(a.x - center.x);
+            let angle_b = (b.y - center.y).atan2(b.x - center.x);
+            angle_a.partial_cmp(&angle_b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        ordered
+    }
+
+    /// Symbolic circles derived from node positions, mirroring the legacy layouts:
+    /// a single enclosing circle for the monad, and a circle around each node of the
+    /// dyad reaching the other node (vesica piscis)
+    pub fn symbolic_circles_for_points(points: &[Point], monad_radius: f64) -> Vec<SymbolicCircle> {
+        match points {
+            [only] => vec![SymbolicCircle { center: only.clone(), radius: monad_radius }],
+            [a, b] => {
+                let radius = ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt();
+                vec![
+                    SymbolicCircle { center: a.clone(), radius },
+                    SymbolicCircle { center: b.clone(), radius },
+                ]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Step length of the chord between two nodes (0-based) of a regular n-gon,
+    /// 0 for a node and itself
+    pub fn chord_step(node_count: usize, from: usize, to: usize) -> usize {
+        let distance = from.abs_diff(to) % node_count.max(1);
+        distance.min(node_count - distance)
+    }
+
+    /// Partition the edges of Kn into chord classes by step length, shortest first
+    pub fn chord_classes(node_count: usize) -> Vec<ChordClass> {
+        let mut classes: Vec<ChordClass> = (1..=node_count / 2)
+            .map(|step| ChordClass { step, edges: vec![] })
+            .collect();
+
+        for edge in Self::generate_complete_graph_edges(node_count) {
+            let step = Self::chord_step(node_count, edge.from, edge.to);
+            classes[step - 1].edges.push(edge);
+        }
+
+        classes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: &Point, b: &Point) -> bool {
+        (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6
+    }
+
+    /// Rotate by `angle`, scale, mirror if asked, then move by (dx, dy)
+    fn transform(points: &[Point], angle: f64, scale: f64, mirrored: bool, dx: f64, dy: f64) -> Vec<Point> {
+        points.iter()
+            .map(|p| {
+                let y = if mirrored { -p.y } else { p.y };
+                Point {
+                    x: scale * (p.x * angle.cos() - y * angle.sin()) + dx,
+                    y: scale * (p.x * angle.sin() + y * angle.cos()) + dy,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn similarity_recovers_missing_positions() {
+        for node_count in [3, 4, 6, 9] {
+            for mirrored in [false, true] {
+                let procedural = GeometryCalculator::calculate_generic_layout(node_count, 400.0, 400.0, 700.0).nodes;
+                let actual = transform(&procedural, 0.7, 0.3, mirrored, 50.0, -20.0);
+
+                // Only the first three positions are known
+                let fit = Similarity::fit(&procedural[..3], &actual[..3]).expect("three points fit");
+                for (node, expected) in procedural.iter().zip(&actual) {
+                    assert!(close(&fit.apply(node), expected), "K{} mirrored={}", node_count, mirrored);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn similarity_prefers_unmirrored_for_two_points() {
+        let procedural = GeometryCalculator::calculate_generic_layout(6, 400.0, 400.0, 700.0).nodes;
+        let actual = transform(&procedural, 1.2, 2.0, false, -30.0, 10.0);
+        let fit = Similarity::fit(&procedural[..2], &actual[..2]).unwrap();
+        for (node, expected) in procedural.iter().zip(&actual) {
+            assert!(close(&fit.apply(node), expected));
+        }
+    }
+
+    #[test]
+    fn similarity_needs_two_distinct_points() {
+        let p = Point { x: 1.0, y: 2.0 };
+        assert!(Similarity::fit(std::slice::from_ref(&p), std::slice::from_ref(&p)).is_none());
+        assert!(Similarity::fit(&[p.clone(), p.clone()], &[p.clone(), Point { x: 5.0, y: 5.0 }]).is_none());
+    }
+
+    #[test]
+    fn enclosing_circle_contains_every_point() {
+        let points = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 10.0, y: 0.0 },
+            Point { x: 0.0, y: 3.0 },
+        ];
+        let circle = GeometryCalculator::enclosing_circle(&points).unwrap();
+        for p in &points {
+            let distance = ((p.x - circle.center.x).powi(2) + (p.y - circle.center.y).powi(2)).sqrt();
+            assert!(distance <= circle.radius + 1e-9);
+        }
+    }
+
+    #[test]
+    fn chord_steps_wrap_around_the_polygon() {
+        assert_eq!(GeometryCalculator::chord_step(7, 0, 1), 1);
+        assert_eq!(GeometryCalculator::chord_step(7, 1, 0), 1);
+        assert_eq!(GeometryCalculator::chord_step(7, 0, 6), 1);
+        assert_eq!(GeometryCalculator::chord_step(7, 0, 3), 3);
+        assert_eq!(GeometryCalculator::chord_step(7, 0, 4), 3);
+        assert_eq!(GeometryCalculator::chord_step(8, 2, 6), 4);
+        assert_eq!(GeometryCalculator::chord_step(5, 3, 3), 0);
+    }
+
+    #[test]
+    fn chord_classes_partition_kn() {
+        assert!(GeometryCalculator::chord_classes(1).is_empty());
+        for n in 2..=12 {
+            let classes = GeometryCalculator::chord_classes(n);
+            assert_eq!(classes.len(), n / 2);
+            assert_eq!(classes.iter().map(|c| c.edges.len()).sum::<usize>(), n * (n - 1) / 2);
+            for class in &classes {
+                // Every step has a full polygon's worth of chords, except the diameters
+                let expected = if 2 * class.step == n { n / 2 } else { n };
+                assert_eq!(class.edges.len(), expected, "K{} {}", n, class.schlafli(n));
+                assert!(class.edges.iter().all(|e| GeometryCalculator::chord_step(n, e.from, e.to) == class.step));
+            }
+        }
+        assert_eq!(GeometryCalculator::chord_classes(7)[1].schlafli(7), "{7/2}");
+    }
+
+    #[test]
+    fn only_the_polygon_keeps_position_order() {
+        let cyclic: Vec<LayoutKind> = LayoutKind::ALL.into_iter().filter(LayoutKind::has_cyclic_order).collect();
+        assert_eq!(cyclic, vec![LayoutKind::RegularPolygon]);
+    }
 }
@@ -0,0 +1,356 @@
+use crate::core::geometry::{GeometryCalculator, Point};
+
+/// Advance widths for printable ASCII (U+0020..=U+007E) in 1/1000 em,
+/// taken from the Helvetica/Arial metrics that the UI font stack falls back to
+const ASCII_ADVANCES: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // ' '..'/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, // '0'..'?'
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, // '@'..'O'
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556, // 'P'..'_'
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, // '`'..'o'
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,      // 'p'..'~'
+];
+
+/// Advance width used for glyphs outside the table
+const DEFAULT_ADVANCE: u16 = 556;
+
+/// Advance width for full-width (CJK, fullwidth forms) glyphs
+const WIDE_ADVANCE: u16 = 1000;
+
+/// Measure the rendered width of `text` at `font_size` pixels using glyph advance widths
+pub fn measure_text(text: &str, font_size: f64) -> f64 {
+    let units: u32 = text.chars().map(|c| glyph_advance(c) as u32).sum();
+    units as f64 * font_size / 1000.0
+}
+
+fn glyph_advance(c: char) -> u16 {
+    match c as u32 {
+        0x20..=0x7E => ASCII_ADVANCES[(c as u32 - 0x20) as usize],
+        // Combining diacritical marks take no horizontal space
+        0x0300..=0x036F => 0,
+        0x1100..=0x115F | 0x2E80..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFF00..=0xFF60 => WIDE_ADVANCE,
+        _ => DEFAULT_ADVANCE,
+    }
+}
+
+/// A label that needs to be placed along an edge
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelRequest {
+    pub from: Point,
+    pub to: Point,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Where a label ended up after the placement pass
+#[derive(Debug, Clone)]
+pub struct LabelPlacement {
+    /// Centre of the label box
+    pub center: Point,
+    /// Rotation in degrees, already flipped so text is never upside down
+    pub angle: f64,
+    /// Point on the edge the label belongs to (differs from `center` when a leader line is used)
+    pub anchor: Point,
+    /// Whether the label was pushed off its edge and needs a leader line
+    pub leader: bool,
+}
+
+/// Oriented rectangle used for overlap tests
+#[derive(Debug, Clone)]
+struct LabelBox {
+    center: Point,
+    half_width: f64,
+    half_height: f64,
+    /// Unit vector along the box's width
+    axis: (f64, f64),
+}
+
+impl LabelBox {
+    fn new(center: Point, width: f64, height: f64, axis: (f64, f64), padding: f64) -> Self {
+        Self {
+            center,
+            half_width: width / 2.0 + padding,
+            half_height: height / 2.0 + padding,
+            axis,
+        }
+    }
+
+    /// Half-length of this box's projection onto `dir`
+    fn projected_radius(&self, dir: (f64, f64)) -> f64 {
+        let (ux, uy) = self.axis;
+        let (vx, vy) = (-uy, ux);
+        self.half_width * (ux * dir.0 + uy * dir.1).abs()
+            + self.half_height * (vx * dir.0 + vy * dir.1).abs()
+    }
+
+    /// Radius of the circle enclosing this box
+    fn bounding_radius(&self) -> f64 {
+        (self.half_width * self.half_width + self.half_height * self.half_height).sqrt()
+    }
+
+    /// Separating-axis test between two oriented rectangles
+    fn overlaps(&self, other: &LabelBox) -> bool {
+        let d = (other.center.x - self.center.x, other.center.y - self.center.y);
+
+        // Cheap rejection for distant boxes, which is most pairs on large systems
+        let reach = self.bounding_radius() + other.bounding_radius();
+        if d.0 * d.0 + d.1 * d.1 > reach * reach {
+            return false;
+        }
+        let axes = [
+            self.axis,
+            (-self.axis.1, self.axis.0),
+            other.axis,
+            (-other.axis.1, other.axis.0),
+        ];
+
+        axes.iter().all(|&axis| {
+            let distance = (d.0 * axis.0 + d.1 * axis.1).abs();
+            distance <= self.projected_radius(axis) + other.projected_radius(axis)
+        })
+    }
+
+    /// Whether a circle (e.g. a node) intersects this box
+    fn overlaps_circle(&self, center: &Point, radius: f64) -> bool {
+        let (ux, uy) = self.axis;
+        let dx = center.x - self.center.x;
+        let dy = center.y - self.center.y;
+
+        // Circle centre in the box's local frame, clamped to the box
+        let local_x = dx * ux + dy * uy;
+        let local_y = -dx * uy + dy * ux;
+        let nearest_x = local_x.clamp(-self.half_width, self.half_width);
+        let nearest_y = local_y.clamp(-self.half_height, self.half_height);
+
+        let ex = local_x - nearest_x;
+        let ey = local_y - nearest_y;
+        ex * ex + ey * ey < radius * radius
+    }
+}
+
+/// Fractions along an edge to try, starting from the midpoint and sliding outwards
+const SLIDE_STEPS: [f64; 9] = [0.5, 0.42, 0.58, 0.34, 0.66, 0.26, 0.74, 0.18, 0.82];
+
+/// Number of perpendicular offsets to try before giving up on a leader line
+const LEADER_STEPS: usize = 4;
+
+/// Greedy placement pass that slides labels along their edges to avoid
+/// overlaps, falling back to leader lines when an edge has no free slot
+pub struct LabelPlacer {
+    nodes: Vec<Point>,
+    node_radius: f64,
+    padding: f64,
+}
+
+impl LabelPlacer {
+    pub fn new(nodes: Vec<Point>, node_radius: f64) -> Self {
+        Self {
+            nodes,
+            node_radius,
+            padding: 2.0,
+        }
+    }
+
+    pub fn place(&self, requests: &[LabelRequest]) -> Vec<LabelPlacement> {
+        let centroid = GeometryCalculator::centroid(&self.nodes).unwrap_or(Point { x: 0.0, y: 0.0 });
+
+        // Place labels on short edges first: they have the fewest free slots
+        let mut order: Vec<usize> = (0..requests.len()).collect();
+        order.sort_by(|&a, &b| {
+            edge_length(&requests[a])
+                .partial_cmp(&edge_length(&requests[b]))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut placed: Vec<LabelBox> = Vec::with_capacity(requests.len());
+        let mut placements: Vec<Option<LabelPlacement>> = vec![None; requests.len()];
+
+        for idx in order {
+            let request = &requests[idx];
+            let (placement, label_box) = self.place_one(request, &placed, &centroid);
+            placed.push(label_box);
+            placements[idx] = Some(placement);
+        }
+
+        placements.into_iter().flatten().collect()
+    }
+
+    /// Every label at its edge's midpoint, skipping the placement pass, for when
+    /// the edges are moving too fast for placement to be worth it
+    pub fn unplaced(requests: &[LabelRequest]) -> Vec<LabelPlacement> {
+        requests.iter().map(midpoint_placement).collect()
+    }
+
+    fn place_one(
+        &self,
+        request: &LabelRequest,
+        placed: &[LabelBox],
+        centroid: &Point,
+    ) -> (LabelPlacement, LabelBox) {
+        let dx = request.to.x - request.from.x;
+        let dy = request.to.y - request.from.y;
+        let length = (dx * dx + dy * dy).sqrt().max(f64::EPSILON);
+        let axis = (dx / length, dy / length);
+        let angle = readable_angle(dy.atan2(dx).to_degrees());
+
+        let point_at = |t: f64| Point {
+            x: request.from.x + dx * t,
+            y: request.from.y + dy * t,
+        };
+
+        // 1. Slide along the edge
+        for &t in SLIDE_STEPS.iter() {
+            let center = point_at(t);
+            let candidate = LabelBox::new(center.clone(), request.width, request.height, axis, self.padding);
+            if self.is_free(&candidate, placed) {
+                return (
+                    LabelPlacement { center: center.clone(), angle, anchor: center, leader: false },
+                    candidate,
+                );
+            }
+        }
+
+        // 2. Push the label off the edge midpoint, away from the centroid first
+        let anchor = point_at(0.5);
+        let mut normal = (-axis.1, axis.0);
+        if (anchor.x - centroid.x) * normal.0 + (anchor.y - centroid.y) * normal.1 < 0.0 {
+            normal = (-normal.0, -normal.1);
+        }
+
+        for step in 1..=LEADER_STEPS {
+            let offset = (request.height + self.padding * 2.0) * step as f64;
+            for sign in [1.0, -1.0] {
+                let center = Point {
+                    x: anchor.x + normal.0 * offset * sign,
+                    y: anchor.y + normal.1 * offset * sign,
+                };
+                let candidate = LabelBox::new(center.clone(), request.width, request.height, axis, self.padding);
+                if self.is_free(&candidate, placed) {
+                    return (
+                        LabelPlacement { center, angle, anchor: anchor.clone(), leader: true },
+                        candidate,
+                    );
+                }
+            }
+        }
+
+        // 3. No free slot: keep the label at the midpoint
+        let candidate = LabelBox::new(anchor, request.width, request.height, axis, self.padding);
+        (midpoint_placement(request), candidate)
+    }
+
+    fn is_free(&self, candidate: &LabelBox, placed: &[LabelBox]) -> bool {
+        !self.nodes.iter().any(|node| candidate.overlaps_circle(node, self.node_radius))
+            && !placed.iter().any(|other| candidate.overlaps(other))
+    }
+}
+
+fn midpoint_placement(request: &LabelRequest) -> LabelPlacement {
+    let anchor = Point {
+        x: (request.from.x + request.to.x) / 2.0,
+        y: (request.from.y + request.to.y) / 2.0,
+    };
+    let angle = readable_angle((request.to.y - request.from.y).atan2(request.to.x - request.from.x).to_degrees());
+    LabelPlacement { center: anchor.clone(), angle, anchor, leader: false }
+}
+
+fn edge_length(request: &LabelRequest) -> f64 {
+    let dx = request.to.x - request.from.x;
+    let dy = request.to.y - request.from.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Keep text readable (not upside down)
+fn readable_angle(angle: f64) -> f64 {
+    if !(-90.0..=90.0).contains(&angle) {
+        angle + 180.0
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE_RADIUS: f64 = 12.0;
+
+    /// A label for every edge of Kn laid out as a regular polygon
+    fn polygon_requests(n: usize) -> (Vec<Point>, Vec<LabelRequest>) {
+        let nodes = GeometryCalculator::calculate_generic_layout(n, 400.0, 400.0, 700.0).nodes;
+        let mut requests = Vec::new();
+        for a in 0..n {
+            for b in a + 1..n {
+                requests.push(LabelRequest {
+                    from: nodes[a].clone(),
+                    to: nodes[b].clone(),
+                    width: measure_text("Label", 10.0) + 8.0,
+                    height: 16.0,
+                });
+            }
+        }
+        (nodes, requests)
+    }
+
+    fn placed_box(request: &LabelRequest, placement: &LabelPlacement) -> LabelBox {
+        let dx = request.to.x - request.from.x;
+        let dy = request.to.y - request.from.y;
+        let length = (dx * dx + dy * dy).sqrt();
+        LabelBox::new(placement.center.clone(), request.width, request.height, (dx / length, dy / length), 0.0)
+    }
+
+    #[test]
+    fn placed_labels_avoid_each_other_and_the_nodes() {
+        for n in 5..=12 {
+            let (nodes, requests) = polygon_requests(n);
+            let placements = LabelPlacer::new(nodes.clone(), NODE_RADIUS).place(&requests);
+            assert_eq!(placements.len(), requests.len());
+
+            let boxes: Vec<LabelBox> = requests.iter().zip(&placements)
+                .map(|(request, placement)| placed_box(request, placement))
+                .collect();
+            for (i, label) in boxes.iter().enumerate() {
+                for node in &nodes {
+                    assert!(!label.overlaps_circle(node, NODE_RADIUS), "K{} label {} covers a node", n, i);
+                }
+                for (j, other) in boxes.iter().enumerate().skip(i + 1) {
+                    assert!(!label.overlaps(other), "K{} labels {} and {} overlap", n, i, j);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn boxes_overlap_only_when_they_meet() {
+        let flat = |x: f64, y: f64| LabelBox::new(Point { x, y }, 20.0, 10.0, (1.0, 0.0), 0.0);
+        assert!(flat(0.0, 0.0).overlaps(&flat(15.0, 5.0)));
+        assert!(!flat(0.0, 0.0).overlaps(&flat(25.0, 0.0)));
+        assert!(!flat(0.0, 0.0).overlaps(&flat(0.0, 12.0)));
+
+        // A box turned 45° clears a corner its bounding circle would reach
+        let diagonal = (std::f64::consts::FRAC_1_SQRT_2, std::f64::consts::FRAC_1_SQRT_2);
+        let turned = LabelBox::new(Point { x: 18.0, y: 12.0 }, 20.0, 2.0, diagonal, 0.0);
+        assert!(!flat(0.0, 0.0).overlaps(&turned));
+    }
+
+    #[test]
+    fn circles_overlap_boxes_they_reach() {
+        let label = LabelBox::new(Point { x: 0.0, y: 0.0 }, 20.0, 10.0, (1.0, 0.0), 0.0);
+        assert!(label.overlaps_circle(&Point { x: 0.0, y: 0.0 }, 1.0));
+        assert!(label.overlaps_circle(&Point { x: 14.0, y: 0.0 }, 5.0));
+        assert!(!label.overlaps_circle(&Point { x: 16.0, y: 0.0 }, 5.0));
+        // Near a corner the distance is to the corner, not to either side
+        assert!(!label.overlaps_circle(&Point { x: 14.0, y: 9.0 }, 5.0));
+    }
+
+    #[test]
+    fn longer_text_measures_wider() {
+        assert_eq!(measure_text("", 10.0), 0.0);
+        assert!(measure_text("Tetrad", 10.0) > measure_text("Tet", 10.0));
+        assert!(measure_text("W", 10.0) > measure_text("i", 10.0));
+        assert!(measure_text("ab", 20.0) > measure_text("ab", 10.0));
+        // Combining marks add nothing; full-width glyphs take a whole em
+        assert_eq!(measure_text("e\u{0301}", 10.0), measure_text("e", 10.0));
+        assert_eq!(measure_text("漢", 10.0), 10.0);
+    }
+}
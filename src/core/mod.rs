@@ -1,2 +1,11 @@
 pub mod geometry;
 pub mod system_config;
+pub mod labels;
+pub mod system_kind;
+pub mod subsystems;
+pub mod decomposition;
+pub mod symmetry;
+pub mod fuzzy;
+pub mod theme;
+pub mod colour;
+pub mod palette;
@@ -0,0 +1,224 @@
+use crate::core::theme::GraphTheme;
+
+/// Where node colours come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaletteKind {
+    /// The API's `colours`, with the theme's node colour for gaps
+    #[default]
+    Api,
+    /// Okabe–Ito colours, distinguishable with the common colour-vision deficiencies
+    ColourBlindSafe,
+    HighContrast,
+    /// Greys only, for printing
+    Greyscale,
+}
+
+/// How a palette treats colours the API does provide
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaletteMode {
+    /// Keep API colours and use the palette only where they're missing
+    #[default]
+    FillGaps,
+    /// Swap each distinct API colour for a palette colour, keeping which terms share a colour
+    Remap,
+    /// Ignore API colours and colour every position from the palette
+    Override,
+}
+
+/// A palette and the mode it's applied in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Palette {
+    pub kind: PaletteKind,
+    pub mode: PaletteMode,
+}
+
+const COLOUR_BLIND_SAFE: &[&str] = &[
+    "#0072B2", "#E69F00", "#009E73", "#CC79A7", "#56B4E9", "#D55E00", "#F0E442", "#000000",
+];
+/// Dark wine, outside the palette so a selected node never looks like a plain one
+const COLOUR_BLIND_SAFE_SELECTION: &str = "#882255";
+const HIGH_CONTRAST: &[&str] = &[
+    "#0000CC", "#CC0000", "#007A00", "#000000", "#B000B0", "#CC6600", "#007A7A", "#5A3A00",
+];
+const GREYSCALE: &[&str] = &["#1A1A1A", "#555555", "#8C8C8C", "#BFBFBF", "#E6E6E6"];
+
+impl PaletteKind {
+    pub const ALL: [PaletteKind; 4] = [
+        PaletteKind::Api,
+        PaletteKind::ColourBlindSafe,
+        PaletteKind::HighContrast,
+        PaletteKind::Greyscale,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PaletteKind::Api => "API colours",
+            PaletteKind::ColourBlindSafe => "Colour-blind safe",
+            PaletteKind::HighContrast => "High contrast",
+            PaletteKind::Greyscale => "Greyscale",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            PaletteKind::Api => "api",
+            PaletteKind::ColourBlindSafe => "colour-blind-safe",
+            PaletteKind::HighContrast => "high-contrast",
+            PaletteKind::Greyscale => "greyscale",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<PaletteKind> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    /// Node colours, cycled when a system has more positions than colours
+    pub fn colours(&self) -> &'static [&'static str] {
+        match self {
+            PaletteKind::Api => &[],
+            PaletteKind::ColourBlindSafe => COLOUR_BLIND_SAFE,
+            PaletteKind::HighContrast => HIGH_CONTRAST,
+            PaletteKind::Greyscale => GREYSCALE,
+        }
+    }
+}
+
+impl PaletteMode {
+    pub const ALL: [PaletteMode; 3] = [PaletteMode::FillGaps, PaletteMode::Remap, PaletteMode::Override];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PaletteMode::FillGaps => "Fill gaps",
+            PaletteMode::Remap => "Remap",
+            PaletteMode::Override => "Override",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            PaletteMode::FillGaps => "fill-gaps",
+            PaletteMode::Remap => "remap",
+            PaletteMode::Override => "override",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<PaletteMode> {
+        Self::ALL.into_iter().find(|mode| mode.key() == key)
+    }
+}
+
+impl Palette {
+    /// Colour for position `index` (0-based), or `None` if the palette has none
+    pub fn colour(&self, index: usize) -> Option<&'static str> {
+        let colours = self.kind.colours();
+        (!colours.is_empty()).then(|| colours[index % colours.len()])
+    }
+
+    /// The theme's graph colours with this palette's edge, selection and class colours
+    pub fn adjust(&self, theme: GraphTheme) -> GraphTheme {
+        match self.kind {
+            PaletteKind::Api => theme,
+            PaletteKind::ColourBlindSafe => GraphTheme {
+                node: COLOUR_BLIND_SAFE[0],
+                edge: "#7F7F7F",
+                selected_node: COLOUR_BLIND_SAFE_SELECTION,
+                selected_edge: COLOUR_BLIND_SAFE_SELECTION,
+                edge_classes: &["#0072B2", "#E69F00", "#009E73", "#CC79A7", "#56B4E9", "#D55E00"],
+                ..theme
+            },
+            PaletteKind::HighContrast => GraphTheme {
+                node: HIGH_CONTRAST[0],
+                node_stroke: "#000000",
+                term_text: "#000000",
+                edge: "#000000",
+                selected_node: "#FFB000",
+                selected_edge: "#FFB000",
+                label_text: "#000000",
+                label_background: "#FFFFFF",
+                label_border: "#000000",
+                leader: "#000000",
+                edge_classes: &["#0000CC", "#CC0000", "#007A00", "#B000B0", "#CC6600", "#007A7A"],
+                ..theme
+            },
+            PaletteKind::Greyscale => GraphTheme {
+                node: GREYSCALE[1],
+                node_stroke: "#FFFFFF",
+                term_text: "#000000",
+                edge: "#8C8C8C",
+                selected_node: "#000000",
+                selected_edge: "#000000",
+                label_text: "#000000",
+                label_background: "#FFFFFF",
+                label_border: "#8C8C8C",
+                leader: "#8C8C8C",
+                overlay: "#BFBFBF",
+                edge_classes: &["#1A1A1A", "#555555", "#8C8C8C", "#BFBFBF"],
+                ..theme
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::colour::{readable_text_on, Rgb};
+
+    /// WCAG AA contrast for normal text, which each node's position number must meet
+    const TEXT_CONTRAST: f64 = 4.5;
+
+    /// WCAG AA contrast for graphics against the page
+    const GRAPHIC_CONTRAST: f64 = 3.0;
+
+    #[test]
+    fn position_numbers_read_on_every_palette_colour() {
+        for kind in PaletteKind::ALL {
+            for colour in kind.colours() {
+                let fill = Rgb::parse(colour).unwrap();
+                let (text, _) = readable_text_on(colour).unwrap();
+                let contrast = fill.contrast(&Rgb::parse(text).unwrap());
+                assert!(contrast >= TEXT_CONTRAST, "{} text on {} is {:.2}:1", text, colour, contrast);
+            }
+        }
+    }
+
+    #[test]
+    fn high_contrast_colours_stand_out_on_white() {
+        let white = Rgb(255, 255, 255);
+        for colour in PaletteKind::HighContrast.colours() {
+            let contrast = Rgb::parse(colour).unwrap().contrast(&white);
+            assert!(contrast >= GRAPHIC_CONTRAST, "{} is {:.2}:1 on white", colour, contrast);
+        }
+    }
+
+    #[test]
+    fn palettes_cycle_and_the_api_has_none() {
+        let palette = |kind| Palette { kind, mode: PaletteMode::default() };
+        assert_eq!(palette(PaletteKind::Api).colour(0), None);
+        let safe = palette(PaletteKind::ColourBlindSafe);
+        assert_eq!(safe.colour(0), safe.colour(COLOUR_BLIND_SAFE.len()));
+    }
+
+    #[test]
+    fn keys_round_trip() {
+        for kind in PaletteKind::ALL {
+            assert_eq!(PaletteKind::from_key(kind.key()), Some(kind));
+        }
+        for mode in PaletteMode::ALL {
+            assert_eq!(PaletteMode::from_key(mode.key()), Some(mode));
+        }
+    }
+
+    #[test]
+    fn selection_stands_out_from_the_palette() {
+        for kind in PaletteKind::ALL {
+            let theme = Palette { kind, mode: PaletteMode::default() }.adjust(GraphTheme::default());
+            for selected in [theme.selected_node, theme.selected_edge] {
+                assert!(
+                    !kind.colours().iter().any(|c| c.eq_ignore_ascii_case(selected)),
+                    "{} selects with its own colour {}", kind.label(), selected,
+                );
+            }
+        }
+    }
+}
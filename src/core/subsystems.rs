@@ -0,0 +1,110 @@
+/// Number of m-subsets of an n-set, or `None` if it doesn't fit in a `usize`
+/// (on wasm32 that's already C(34, 17))
+pub fn binomial(n: usize, m: usize) -> Option<usize> {
+    if m > n {
+        return Some(0);
+    }
+    let m = m.min(n - m);
+    // Each step is C(n, i + 1), which only grows up to m = n / 2, so once one step
+    // overflows the result would too. The product is widened so it can't overflow first.
+    (0..m).try_fold(1usize, |acc, i| {
+        usize::try_from(acc as u128 * (n - i) as u128 / (i + 1) as u128).ok()
+    })
+}
+
+/// The `index`-th m-subset of {0, .., n-1} in lexicographic order.
+///
+/// Unranking lets a paged list show any slice of the subsets without
+/// enumerating everything before it (K24 has 2.7 million 12-subsets).
+/// `None` past the last subset, or if there are too many to count.
+pub fn combination_at(n: usize, m: usize, mut index: usize) -> Option<Vec<usize>> {
+    if index >= binomial(n, m)? {
+        return None;
+    }
+
+    let mut subset = Vec::with_capacity(m);
+    let mut candidate = 0;
+
+    for slot in 0..m {
+        loop {
+            // Subsets that start this slot with `candidate`; no more than all of them, so it fits
+            let count = binomial(n - candidate - 1, m - slot - 1)?;
+            if index < count {
+                break;
+            }
+            index -= count;
+            candidate += 1;
+        }
+        subset.push(candidate);
+        candidate += 1;
+    }
+
+    Some(subset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pascal's triangle up to `rows`, exact in u128 (C(99, 49) is about 5e28)
+    fn pascal(rows: usize) -> Vec<Vec<u128>> {
+        let mut triangle: Vec<Vec<u128>> = vec![vec![1]];
+        for n in 1..=rows {
+            let previous = &triangle[n - 1];
+            let row = (0..=n)
+                .map(|m| if m == 0 || m == n { 1 } else { previous[m - 1] + previous[m] })
+                .collect();
+            triangle.push(row);
+        }
+        triangle
+    }
+
+    /// Every m-subset of {0, .., n-1} in lexicographic order, by brute force
+    fn all_subsets(n: usize, m: usize) -> Vec<Vec<usize>> {
+        let mut subsets: Vec<Vec<usize>> = (0..1usize << n)
+            .filter(|bits| bits.count_ones() as usize == m)
+            .map(|bits| (0..n).filter(|i| bits & (1 << i) != 0).collect())
+            .collect();
+        subsets.sort();
+        subsets
+    }
+
+    #[test]
+    fn binomial_matches_pascal_until_it_overflows() {
+        for (n, row) in pascal(99).iter().enumerate() {
+            for (m, &exact) in row.iter().enumerate() {
+                assert_eq!(binomial(n, m), usize::try_from(exact).ok(), "C({}, {})", n, m);
+            }
+            assert_eq!(binomial(n, n + 1), Some(0));
+        }
+        assert_eq!(binomial(24, 12), Some(2_704_156));
+        assert_eq!(binomial(99, 49), None);
+    }
+
+    #[test]
+    fn combinations_enumerate_every_subset_in_order() {
+        for n in 0..=9 {
+            for m in 0..=n {
+                let expected = all_subsets(n, m);
+                let total = binomial(n, m).unwrap();
+                assert_eq!(total, expected.len());
+
+                let unranked: Vec<Vec<usize>> = (0..total).map(|i| combination_at(n, m, i).unwrap()).collect();
+                assert_eq!(unranked, expected, "K{} {}-subsets", n, m);
+                assert_eq!(combination_at(n, m, total), None);
+            }
+        }
+    }
+
+    #[test]
+    fn combinations_reach_the_overflow_boundary() {
+        // The largest middle binomial that still fits
+        let n = (2..).find(|&n| binomial(n + 1, n.div_ceil(2)).is_none()).unwrap();
+        let m = n / 2;
+        let total = binomial(n, m).unwrap();
+
+        assert_eq!(combination_at(n, m, 0), Some((0..m).collect()));
+        assert_eq!(combination_at(n, m, total - 1), Some((n - m..n).collect()));
+        assert_eq!(combination_at(n + 1, n.div_ceil(2), 0), None);
+    }
+}
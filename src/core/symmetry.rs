@@ -0,0 +1,274 @@
+use std::fmt;
+
+/// A permutation of the positions of a system, 0-based: position `i` maps to `images[i]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    images: Vec<usize>,
+}
+
+impl Permutation {
+    pub fn identity(n: usize) -> Self {
+        Self { images: (0..n).collect() }
+    }
+
+    /// Build from images, or `None` if they aren't a permutation of 0..n
+    pub fn from_images(images: Vec<usize>) -> Option<Self> {
+        let mut seen = vec![false; images.len()];
+        for &image in &images {
+            if image >= images.len() || std::mem::replace(&mut seen[image], true) {
+                return None;
+            }
+        }
+        Some(Self { images })
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.images.iter().enumerate().all(|(i, &image)| i == image)
+    }
+
+    /// Where position `i` goes (positions outside the permutation stay put)
+    pub fn apply(&self, i: usize) -> usize {
+        self.images.get(i).copied().unwrap_or(i)
+    }
+
+    /// `self` after `first`: position i goes to self(first(i))
+    pub fn after(&self, first: &Permutation) -> Permutation {
+        Permutation {
+            images: (0..first.images.len()).map(|i| self.apply(first.apply(i))).collect(),
+        }
+    }
+
+    /// Disjoint cycles in 1-based notation, e.g. "(1 2 3)(4 5)"; "()" for the identity
+    fn cycle_notation(&self) -> String {
+        let mut visited = vec![false; self.images.len()];
+        let mut out = String::new();
+
+        for start in 0..self.images.len() {
+            if visited[start] || self.images[start] == start {
+                continue;
+            }
+            let mut cycle = vec![];
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                cycle.push((i + 1).to_string());
+                i = self.images[i];
+            }
+            out.push_str(&format!("({})", cycle.join(" ")));
+        }
+
+        if out.is_empty() {
+            "()".to_string()
+        } else {
+            out
+        }
+    }
+}
+
+impl fmt::Display for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cycle_notation())
+    }
+}
+
+/// Error returned when a string isn't a valid permutation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsePermutationError(String);
+
+impl fmt::Display for ParsePermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid permutation: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePermutationError {}
+
+impl Permutation {
+    /// Parse a permutation of `n` positions, 1-based, in either cycle notation
+    /// ("(1 2 3)(4 5)") or one-line notation listing each position's image ("2 3 1 5 4")
+    pub fn parse(s: &str, n: usize) -> Result<Permutation, ParsePermutationError> {
+        let s = s.trim();
+        if s.contains('(') {
+            Self::parse_cycles(s, n)
+        } else {
+            let images = parse_positions(s, n)?;
+            if images.len() != n {
+                return Err(ParsePermutationError(format!("expected {} images, found {}", n, images.len())));
+            }
+            Permutation::from_images(images)
+                .ok_or_else(|| ParsePermutationError("positions repeat".to_string()))
+        }
+    }
+
+    /// Disjoint cycles: each position may appear once in the whole string, so "(1 1)"
+    /// and "(1 2)(2 3)" are rejected rather than read as a product
+    fn parse_cycles(s: &str, n: usize) -> Result<Permutation, ParsePermutationError> {
+        if !s.ends_with(')') {
+            return Err(ParsePermutationError(format!("'{}' is missing a closing ')'", s)));
+        }
+        let mut result = Permutation::identity(n);
+        let mut used = vec![false; n];
+
+        for cycle in s.split(')') {
+            let cycle = cycle.trim();
+            if cycle.is_empty() {
+                continue;
+            }
+            let body = cycle.strip_prefix('(')
+                .ok_or_else(|| ParsePermutationError(format!("unbalanced '{}'", cycle)))?;
+            let positions = parse_positions(body, n)?;
+            for &position in &positions {
+                if std::mem::replace(&mut used[position], true) {
+                    return Err(ParsePermutationError(format!("{} appears more than once", position + 1)));
+                }
+            }
+
+            let mut images: Vec<usize> = (0..n).collect();
+            for (i, &position) in positions.iter().enumerate() {
+                images[position] = positions[(i + 1) % positions.len()];
+            }
+            let cycle = Permutation::from_images(images)
+                .ok_or_else(|| ParsePermutationError(format!("positions repeat in '{}'", cycle)))?;
+
+            // Cycles are applied right to left, as in standard notation
+            result = result.after(&cycle);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parse 1-based positions separated by spaces or commas into 0-based indices
+fn parse_positions(s: &str, n: usize) -> Result<Vec<usize>, ParsePermutationError> {
+    s.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| match token.parse::<usize>() {
+            Ok(position) if (1..=n).contains(&position) => Ok(position - 1),
+            _ => Err(ParsePermutationError(format!("'{}' is not a position from 1 to {}", token, n))),
+        })
+        .collect()
+}
+
+/// An element of the dihedral group Dn acting on a regular n-gon's positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DihedralAction {
+    /// Rotate every position k steps around the polygon
+    Rotation(usize),
+    /// Reflect across the axis through position 1 after rotating k steps: i -> k - i
+    Reflection(usize),
+}
+
+impl DihedralAction {
+    /// All 2n elements of Dn, identity first
+    pub fn all(n: usize) -> Vec<DihedralAction> {
+        (0..n).map(DihedralAction::Rotation)
+            .chain((0..n).map(DihedralAction::Reflection))
+            .collect()
+    }
+
+    pub fn label(&self, n: usize) -> String {
+        match *self {
+            DihedralAction::Rotation(0) => "Identity".to_string(),
+            DihedralAction::Rotation(k) => format!("Rotate {:.1}° (r{})", k as f64 * 360.0 / n.max(1) as f64, superscript(k)),
+            DihedralAction::Reflection(k) => format!("Reflect s·r{}", superscript(k)),
+        }
+    }
+
+    pub fn permutation(&self, n: usize) -> Permutation {
+        let images = (0..n)
+            .map(|i| match *self {
+                DihedralAction::Rotation(k) => (i + k) % n,
+                DihedralAction::Reflection(k) => (k % n + n - i) % n,
+            })
+            .collect();
+        Permutation { images }
+    }
+}
+
+fn superscript(k: usize) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    k.to_string()
+        .chars()
+        .filter_map(|c| c.to_digit(10).map(|d| DIGITS[d as usize]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn images(p: &Permutation) -> Vec<usize> {
+        (0..p.images.len()).map(|i| p.apply(i)).collect()
+    }
+
+    #[test]
+    fn parses_one_line_notation() {
+        let p = Permutation::parse("2 3 1 5 4", 5).unwrap();
+        assert_eq!(images(&p), vec![1, 2, 0, 4, 3]);
+        assert_eq!(Permutation::parse("2,1", 2).unwrap(), DihedralAction::Rotation(1).permutation(2));
+        assert!(Permutation::parse("1 2", 3).is_err());
+        assert!(Permutation::parse("1 1 2", 3).is_err());
+        assert!(Permutation::parse("1 2 4", 3).is_err());
+        assert!(Permutation::parse("1 x 2", 3).is_err());
+    }
+
+    #[test]
+    fn parses_disjoint_cycles() {
+        let p = Permutation::parse("(1 2 3)(4 5)", 5).unwrap();
+        assert_eq!(images(&p), vec![1, 2, 0, 4, 3]);
+        assert_eq!(Permutation::parse("(1 2 3)(4 5)", 5), Permutation::parse("2 3 1 5 4", 5));
+        assert!(Permutation::parse("()", 3).unwrap().is_identity());
+        assert!(Permutation::parse(" (1) (2 , 3) ", 3).is_ok());
+    }
+
+    #[test]
+    fn rejects_repeated_or_unbalanced_cycles() {
+        for bad in ["(1 1)", "(1 2)(2 3)", "(1 2)(3 1)", "(1 2", "1 2)", "(1 (2 3))", "(0 1)", "(1 4)"] {
+            assert!(Permutation::parse(bad, 3).is_err(), "{:?} parsed", bad);
+        }
+        assert_eq!(
+            Permutation::parse("(1 2)(2 3)", 3).unwrap_err().to_string(),
+            "Invalid permutation: 2 appears more than once",
+        );
+    }
+
+    #[test]
+    fn cycle_notation_round_trips() {
+        for text in ["()", "(1 2)", "(1 3 2)", "(1 2)(3 4 5)", "(2 6)(3 5)"] {
+            let p = Permutation::parse(text, 6).unwrap();
+            assert_eq!(p.to_string(), text);
+            assert_eq!(Permutation::parse(&p.to_string(), 6).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn dihedral_actions_compose_like_dn() {
+        let n = 7;
+        let p = |action: DihedralAction| action.permutation(n);
+        for a in 0..n {
+            for b in 0..n {
+                let r = |k| DihedralAction::Rotation(k % n);
+                let s = |k| DihedralAction::Reflection(k % n);
+                assert_eq!(p(r(a)).after(&p(r(b))), p(r(a + b)));
+                // A reflection after a reflection is the rotation between their axes
+                assert_eq!(p(s(a)).after(&p(s(b))), p(r(a + n - b)));
+                assert_eq!(p(s(a)).after(&p(r(b))), p(s(a + n - b)));
+            }
+        }
+
+        let all: Vec<Permutation> = DihedralAction::all(n).into_iter().map(p).collect();
+        assert_eq!(all.len(), 2 * n);
+        assert!(all[0].is_identity());
+        for (i, x) in all.iter().enumerate() {
+            assert!(all[i + 1..].iter().all(|y| y != x), "Dn elements repeat");
+        }
+    }
+
+    #[test]
+    fn rotation_labels_keep_fractional_degrees() {
+        assert_eq!(DihedralAction::Rotation(1).label(7), "Rotate 51.4° (r¹)");
+        assert_eq!(DihedralAction::Rotation(1).label(4), "Rotate 90.0° (r¹)");
+        assert_eq!(DihedralAction::Rotation(0).label(4), "Identity");
+        assert_eq!(DihedralAction::Reflection(12).label(13), "Reflect s·r¹²");
+    }
+}
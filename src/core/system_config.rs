@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use crate::core::system_kind::SystemKind;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SystemConfig {
-    pub name: String,
+    /// Which system this configures; `name` in the JSON, e.g. "tetrad"
+    #[serde(rename = "name")]
+    pub kind: SystemKind,
     pub display_name: String,
     pub node_count: usize,
     pub k_notation: String,
@@ -18,171 +21,260 @@ pub struct ColorScheme {
     pub selected_edge: String,
 }
 
+/// Colours for a system with no config of its own
+impl Default for ColorScheme {
+    fn default() -> Self {
+        ColorScheme {
+            nodes: "#4A90E2".to_string(),
+            edges: "#888888".to_string(),
+            selected_node: "#FF6B6B".to_string(),
+            selected_edge: "#FF6B6B".to_string(),
+        }
+    }
+}
+
+/// The bundled configs, embedded at build time from `configs/*.json`
+const BUNDLED_CONFIGS: [(&str, &str); 12] = [
+    ("monad.json", include_str!("../../configs/monad.json")),
+    ("dyad.json", include_str!("../../configs/dyad.json")),
+    ("triad.json", include_str!("../../configs/triad.json")),
+    ("tetrad.json", include_str!("../../configs/tetrad.json")),
+    ("pentad.json", include_str!("../../configs/pentad.json")),
+    ("hexad.json", include_str!("../../configs/hexad.json")),
+    ("heptad.json", include_str!("../../configs/heptad.json")),
+    ("octad.json", include_str!("../../configs/octad.json")),
+    ("ennead.json", include_str!("../../configs/ennead.json")),
+    ("decad.json", include_str!("../../configs/decad.json")),
+    ("undecad.json", include_str!("../../configs/undecad.json")),
+    ("dodecad.json", include_str!("../../configs/dodecad.json")),
+];
+
+/// A user config file holds either one system or a list of them
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ConfigFile {
+    Many(Vec<SystemConfig>),
+    One(SystemConfig),
+}
+
+/// Problem with a system config
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// The file isn't valid JSON for a config
+    Parse { source: String, message: String },
+    /// Two configs for the same system
+    DuplicateName(SystemKind),
+    /// `node_count` disagrees with the system's order or its `k_notation`
+    NodeCountMismatch { kind: SystemKind, node_count: usize, k_notation: String },
+    /// A `color_scheme` entry that isn't `#rgb` or `#rrggbb`
+    InvalidColour { kind: SystemKind, field: &'static str, value: String },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Parse { source, message } => write!(f, "{}: {}", source, message),
+            ConfigError::DuplicateName(kind) => write!(f, "{} is configured more than once", kind),
+            ConfigError::NodeCountMismatch { kind, node_count, k_notation } => write!(
+                f, "{} has node_count {} but is {} ({})", kind, node_count, kind.k_notation(), k_notation,
+            ),
+            ConfigError::InvalidColour { kind, field, value } => {
+                write!(f, "{} color_scheme.{} '{}' is not a hex colour", kind, field, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Whether `value` is a `#rgb` or `#rrggbb` colour
+fn is_hex_colour(value: &str) -> bool {
+    value.strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
 impl SystemConfig {
+    /// The bundled configs, in order.
+    ///
+    /// Panics if a bundled file is malformed: they ship with the app, so that's a build mistake.
     pub fn get_all_systems() -> Vec<SystemConfig> {
-        vec![
-            SystemConfig {
-                name: "monad".to_string(),
-                display_name: "Monad".to_string(),
-                node_count: 1,
-                k_notation: "K1".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#4A90E2".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "dyad".to_string(),
-                display_name: "Dyad".to_string(),
-                node_count: 2,
-                k_notation: "K2".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#50C878".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "triad".to_string(),
-                display_name: "Triad".to_string(),
-                node_count: 3,
-                k_notation: "K3".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#9B59B6".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "tetrad".to_string(),
-                display_name: "Tetrad".to_string(),
-                node_count: 4,
-                k_notation: "K4".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#E74C3C".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "pentad".to_string(),
-                display_name: "Pentad".to_string(),
-                node_count: 5,
-                k_notation: "K5".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#F39C12".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "hexad".to_string(),
-                display_name: "Hexad".to_string(),
-                node_count: 6,
-                k_notation: "K6".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#1ABC9C".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "heptad".to_string(),
-                display_name: "Heptad".to_string(),
-                node_count: 7,
-                k_notation: "K7".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#3498DB".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "octad".to_string(),
-                display_name: "Octad".to_string(),
-                node_count: 8,
-                k_notation: "K8".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#E67E22".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "ennead".to_string(),
-                display_name: "Ennead".to_string(),
-                node_count: 9,
-                k_notation: "K9".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#9B59B6".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "decad".to_string(),
-                display_name: "Decad".to_string(),
-                node_count: 10,
-                k_notation: "K10".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#16A085".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "undecad".to_string(),
-                display_name: "Undecad".to_string(),
-                node_count: 11,
-                k_notation: "K11".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#C0392B".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-            SystemConfig {
-                name: "dodecad".to_string(),
-                display_name: "Dodecad".to_string(),
-                node_count: 12,
-                k_notation: "K12".to_string(),
-                description: "".to_string(),
-                color_scheme: ColorScheme {
-                    nodes: "#D35400".to_string(),
-                    edges: "#888888".to_string(),
-                    selected_node: "#FF6B6B".to_string(),
-                    selected_edge: "#FF6B6B".to_string(),
-                },
-            },
-        ]
+        Self::bundled().to_vec()
+    }
+
+    /// The bundled configs, parsed and checked on first use
+    fn bundled() -> &'static [SystemConfig] {
+        static BUNDLED: std::sync::OnceLock<Vec<SystemConfig>> = std::sync::OnceLock::new();
+        BUNDLED.get_or_init(|| {
+            let configs: Vec<SystemConfig> = BUNDLED_CONFIGS.iter()
+                .map(|(source, json)| Self::parse(source, json).map(|mut configs| configs.remove(0)))
+                .collect::<Result<_, _>>()
+                .unwrap_or_else(|e| panic!("bundled system config is malformed: {}", e));
+
+            if let Some(e) = Self::validate(&configs).first() {
+                panic!("bundled system config is invalid: {}", e);
+            }
+            configs
+        })
+    }
+
+    /// Parse a config file holding one system or a list of them. `source` names the file in errors.
+    pub fn parse(source: &str, json: &str) -> Result<Vec<SystemConfig>, ConfigError> {
+        match serde_json::from_str(json) {
+            Ok(ConfigFile::Many(configs)) => Ok(configs),
+            Ok(ConfigFile::One(config)) => Ok(vec![config]),
+            Err(e) => Err(ConfigError::Parse { source: source.to_string(), message: e.to_string() }),
+        }
+    }
+
+    /// Every problem with a set of configs, empty if they're all valid
+    pub fn validate(configs: &[SystemConfig]) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+
+        for config in configs {
+            if !seen.insert(config.kind) {
+                errors.push(ConfigError::DuplicateName(config.kind));
+            }
+
+            let k_order = config.k_notation.strip_prefix('K').and_then(|n| n.parse::<usize>().ok());
+            if config.node_count != config.kind.order() || k_order != Some(config.node_count) {
+                errors.push(ConfigError::NodeCountMismatch {
+                    kind: config.kind,
+                    node_count: config.node_count,
+                    k_notation: config.k_notation.clone(),
+                });
+            }
+
+            let scheme = &config.color_scheme;
+            for (field, value) in [
+                ("nodes", &scheme.nodes),
+                ("edges", &scheme.edges),
+                ("selected_node", &scheme.selected_node),
+                ("selected_edge", &scheme.selected_edge),
+            ] {
+                if !is_hex_colour(value) {
+                    errors.push(ConfigError::InvalidColour { kind: config.kind, field, value: value.clone() });
+                }
+            }
+        }
+        errors
+    }
+
+    /// `base` with each of `overrides` replacing the config of the same name or, for a new
+    /// system, added to it. The result is sorted by order.
+    ///
+    /// The overrides are validated on their own first, so two overrides for the same system
+    /// are rejected rather than one silently winning, and then the combined set is validated.
+    pub fn merged(base: Vec<SystemConfig>, overrides: Vec<SystemConfig>) -> Result<Vec<SystemConfig>, Vec<ConfigError>> {
+        let errors = Self::validate(&overrides);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut configs = base;
+        for config in overrides {
+            match configs.iter_mut().find(|c| c.kind == config.kind) {
+                Some(existing) => *existing = config,
+                None => configs.push(config),
+            }
+        }
+        configs.sort_by_key(|c| c.kind);
+
+        let errors = Self::validate(&configs);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(configs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tetrad() -> SystemConfig {
+        SystemConfig::get_all_systems().into_iter()
+            .find(|c| c.k_notation == "K4")
+            .expect("tetrad is bundled")
+    }
+
+    #[test]
+    fn bundled_configs_parse_and_validate() {
+        let configs = SystemConfig::get_all_systems();
+        assert_eq!(configs.len(), BUNDLED_CONFIGS.len());
+        assert_eq!(SystemConfig::validate(&configs), vec![]);
+        assert!(configs.windows(2).all(|pair| pair[0].kind < pair[1].kind));
+    }
+
+    #[test]
+    fn parse_accepts_one_config_or_a_list() {
+        let one = serde_json::to_string(&tetrad()).unwrap();
+        let many = format!("[{}, {}]", one, one);
+        assert_eq!(SystemConfig::parse("one.json", &one).unwrap().len(), 1);
+        assert_eq!(SystemConfig::parse("many.json", &many).unwrap().len(), 2);
+        assert!(matches!(
+            SystemConfig::parse("bad.json", "{}"),
+            Err(ConfigError::Parse { ref source, .. }) if source == "bad.json"
+        ));
+    }
+
+    #[test]
+    fn validate_reports_each_problem() {
+        let mut config = tetrad();
+        config.node_count = 5;
+        config.color_scheme.edges = "grey".to_string();
+        let errors = SystemConfig::validate(&[config.clone(), config.clone()]);
+
+        assert!(errors.contains(&ConfigError::DuplicateName(config.kind)));
+        assert!(errors.iter().any(|e| matches!(e, ConfigError::NodeCountMismatch { node_count: 5, .. })));
+        assert!(errors.iter().any(|e| matches!(e, ConfigError::InvalidColour { field: "edges", .. })));
+    }
+
+    #[test]
+    fn overrides_replace_bundled_configs() {
+        let mut config = tetrad();
+        config.display_name = "Four".to_string();
+        let configs = SystemConfig::merged(SystemConfig::get_all_systems(), vec![config.clone()]).unwrap();
+
+        assert_eq!(configs.len(), BUNDLED_CONFIGS.len());
+        assert_eq!(configs.iter().find(|c| c.kind == config.kind), Some(&config));
+    }
+
+    #[test]
+    fn new_systems_are_added_in_order() {
+        let kind = SystemKind::from_order(13).unwrap();
+        let config = SystemConfig {
+            kind,
+            display_name: kind.display_name(),
+            node_count: 13,
+            k_notation: kind.k_notation(),
+            description: String::new(),
+            color_scheme: ColorScheme::default(),
+        };
+        let configs = SystemConfig::merged(SystemConfig::get_all_systems(), vec![config.clone()]).unwrap();
+
+        assert_eq!(configs.len(), BUNDLED_CONFIGS.len() + 1);
+        assert_eq!(configs.last(), Some(&config));
+    }
+
+    #[test]
+    fn merged_set_is_validated() {
+        let mut base = SystemConfig::get_all_systems();
+        base.push(tetrad());
+        let mut config = tetrad();
+        config.kind = SystemKind::from_order(13).unwrap();
+        config.node_count = 13;
+        config.k_notation = "K13".to_string();
+
+        let errors = SystemConfig::merged(base, vec![config]).unwrap_err();
+        assert_eq!(errors, vec![ConfigError::DuplicateName(tetrad().kind)]);
     }
 
-    pub fn get_by_name(name: &str) -> Option<SystemConfig> {
-        Self::get_all_systems()
-            .into_iter()
-            .find(|s| s.name == name)
+    #[test]
+    fn duplicate_overrides_are_rejected() {
+        let config = tetrad();
+        let errors = SystemConfig::merged(SystemConfig::get_all_systems(), vec![config.clone(), config.clone()])
+            .unwrap_err();
+        assert_eq!(errors, vec![ConfigError::DuplicateName(config.kind)]);
     }
 }
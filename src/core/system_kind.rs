@@ -0,0 +1,226 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Lowercase names of the named systems, indexed by order - 1
+const NAMES: [&str; 12] = [
+    "monad", "dyad", "triad", "tetrad", "pentad", "hexad",
+    "heptad", "octad", "ennead", "decad", "undecad", "dodecad",
+];
+
+/// Multiplier prefixes for the units digit of procedurally named systems
+const UNIT_PREFIXES: [&str; 10] = [
+    "", "hen", "do", "tri", "tetra", "penta", "hexa", "hepta", "octa", "ennea",
+];
+
+/// Roots for the tens digit of procedurally named systems (10, 20, ... 90)
+const TENS_ROOTS: [&str; 9] = [
+    "dec", "icos", "triacont", "tetracont", "pentacont", "hexacont", "heptacont", "octacont", "enneacont",
+];
+
+/// Highest order that gets a procedural name; larger systems are known by K-notation
+const MAX_NAMED_ORDER: usize = 99;
+
+/// Which system (complete graph Kn) something refers to, identified by its order.
+///
+/// Parses from names ("tetrad", "Tetrad"), K-notation ("K4") or a bare order ("4"),
+/// and serializes as its lowercase name so it round-trips through `configs/*.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SystemKind(usize);
+
+impl SystemKind {
+    pub const MONAD: SystemKind = SystemKind(1);
+    pub const DYAD: SystemKind = SystemKind(2);
+    pub const DODECAD: SystemKind = SystemKind(12);
+
+    /// The system of the given order, or `None` for order 0
+    pub fn from_order(order: usize) -> Option<SystemKind> {
+        (order >= 1).then_some(SystemKind(order))
+    }
+
+    /// Number of terms (nodes) in the system
+    pub fn order(&self) -> usize {
+        self.0
+    }
+
+    /// Lowercase name, if this system has one.
+    ///
+    /// Monad through Dodecad use their traditional names. Orders 13 to 99 are named
+    /// procedurally from Greek numeral roots (tridecad, icosad, tetracosad, ...).
+    pub fn name(&self) -> Option<String> {
+        if let Some(name) = NAMES.get(self.0 - 1) {
+            return Some(name.to_string());
+        }
+        if self.0 > MAX_NAMED_ORDER {
+            return None;
+        }
+
+        let prefix = UNIT_PREFIXES[self.0 % 10];
+        let root = TENS_ROOTS[self.0 / 10 - 1];
+        // Elide the root's leading 'i' after a vowel: "tetra" + "icos" -> "tetracos"
+        let root = match (prefix.chars().last(), root.strip_prefix('i')) {
+            (Some('a' | 'i' | 'o'), Some(rest)) => rest,
+            _ => root,
+        };
+
+        Some(format!("{}{}ad", prefix, root))
+    }
+
+    /// Stable lowercase identifier: the name, or K-notation for unnamed systems
+    pub fn key(&self) -> String {
+        self.name()
+            .unwrap_or_else(|| self.k_notation().to_lowercase())
+    }
+
+    /// Capitalised name for display
+    pub fn display_name(&self) -> String {
+        match self.name() {
+            Some(name) => {
+                let mut chars = name.chars();
+                chars.next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            None => format!("Order {}", self.0),
+        }
+    }
+
+    /// Complete-graph notation, e.g. "K4"
+    pub fn k_notation(&self) -> String {
+        format!("K{}", self.0)
+    }
+}
+
+impl fmt::Display for SystemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// Error returned when a string doesn't name a system
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseSystemKindError(String);
+
+impl fmt::Display for ParseSystemKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown system '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseSystemKindError {}
+
+impl FromStr for SystemKind {
+    type Err = ParseSystemKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        let digits = lower.strip_prefix('k').unwrap_or(&lower);
+
+        let order = match digits.parse::<usize>() {
+            Ok(order) => Some(order),
+            Err(_) => NAMES.iter().position(|name| *name == lower)
+                .map(|index| index + 1)
+                .or_else(|| procedural_order(&lower)),
+        };
+        order.and_then(SystemKind::from_order)
+            .ok_or_else(|| ParseSystemKindError(s.to_string()))
+    }
+}
+
+/// Order of a procedural name such as "tetracosad", read back from its prefix and root
+fn procedural_order(name: &str) -> Option<usize> {
+    let stem = name.strip_suffix("ad")?;
+    TENS_ROOTS.iter().enumerate().find_map(|(tens, root)| {
+        // The root may have lost its leading 'i' after the prefix
+        [*root, root.strip_prefix('i').unwrap_or(root)].iter().find_map(|root| {
+            let prefix = stem.strip_suffix(root)?;
+            let units = UNIT_PREFIXES.iter().position(|p| *p == prefix)?;
+            let order = (tens + 1) * 10 + units;
+            // Only the spelling `name()` produces counts, e.g. not "tetraicosad"
+            (order > NAMES.len() && SystemKind(order).name().as_deref() == Some(name)).then_some(order)
+        })
+    })
+}
+
+impl Serialize for SystemKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Option<usize> {
+        s.parse::<SystemKind>().ok().map(|kind| kind.order())
+    }
+
+    #[test]
+    fn parses_names_k_notation_and_orders() {
+        assert_eq!(parse("tetrad"), Some(4));
+        assert_eq!(parse(" Tetrad "), Some(4));
+        assert_eq!(parse("K4"), Some(4));
+        assert_eq!(parse("k12"), Some(12));
+        assert_eq!(parse("4"), Some(4));
+        assert_eq!(parse("K150"), Some(150));
+    }
+
+    #[test]
+    fn procedural_names_are_read_back() {
+        assert_eq!(parse("tridecad"), Some(13));
+        assert_eq!(parse("icosad"), Some(20));
+        assert_eq!(parse("henicosad"), Some(21));
+        assert_eq!(parse("docosad"), Some(22));
+        assert_eq!(parse("tetracosad"), Some(24));
+        assert_eq!(parse("enneacontennead"), None);
+        assert_eq!(parse("enneaenneacontad"), Some(99));
+    }
+
+    #[test]
+    fn typos_fail_loudly() {
+        for typo in ["tetrda", "", "K0", "0", "k", "K-1", "tetraicosad", "hendecad", "ad", "dodecads"] {
+            assert!(typo.parse::<SystemKind>().is_err(), "{:?} parsed", typo);
+        }
+        assert_eq!(
+            "tetrda".parse::<SystemKind>().unwrap_err().to_string(),
+            "Unknown system 'tetrda'",
+        );
+    }
+
+    #[test]
+    fn every_name_and_key_round_trips() {
+        for order in 1..=120 {
+            let kind = SystemKind::from_order(order).unwrap();
+            assert_eq!(kind.name().is_some(), order <= MAX_NAMED_ORDER);
+            assert_eq!(kind.key().parse::<SystemKind>(), Ok(kind), "{}", kind.key());
+            assert_eq!(kind.k_notation().parse::<SystemKind>(), Ok(kind));
+        }
+        assert_eq!(SystemKind::from_order(100).unwrap().key(), "k100");
+    }
+
+    #[test]
+    fn serializes_as_its_key() {
+        for order in [1, 4, 12, 13, 24, 99, 100] {
+            let kind = SystemKind::from_order(order).unwrap();
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind.key()));
+            assert_eq!(serde_json::from_str::<SystemKind>(&json).unwrap(), kind);
+        }
+        assert!(serde_json::from_str::<SystemKind>("\"tetrda\"").is_err());
+    }
+
+    #[test]
+    fn display_names_are_capitalised() {
+        assert_eq!(SystemKind::DODECAD.to_string(), "Dodecad");
+        assert_eq!(SystemKind::from_order(24).unwrap().display_name(), "Tetracosad");
+        assert_eq!(SystemKind::from_order(100).unwrap().display_name(), "Order 100");
+    }
+}
@@ -0,0 +1,159 @@
+/// Colours the graph is drawn with, so it follows the page theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphTheme {
+    /// Node fill when the API gives no colour
+    pub node: &'static str,
+    pub node_stroke: &'static str,
+    /// Position number drawn inside each node, and its outline
+    pub node_text: &'static str,
+    pub node_text_outline: &'static str,
+    /// Term label under each node
+    pub term_text: &'static str,
+    pub edge: &'static str,
+    pub selected_node: &'static str,
+    pub selected_edge: &'static str,
+    pub label_text: &'static str,
+    pub label_background: &'static str,
+    pub label_border: &'static str,
+    pub leader: &'static str,
+    pub overlay: &'static str,
+    /// Colours for edge classes such as chord classes or factors (cycled for large systems)
+    pub edge_classes: &'static [&'static str],
+}
+
+impl GraphTheme {
+    /// Colour for the `index`-th edge class (0-based)
+    pub fn edge_class(&self, index: usize) -> &'static str {
+        self.edge_classes[index % self.edge_classes.len()]
+    }
+}
+
+const EDGE_CLASS_COLORS: &[&str] = &["#2563eb", "#e11d48", "#16a34a", "#d97706", "#7c3aed", "#0891b2"];
+
+impl Default for GraphTheme {
+    fn default() -> Self {
+        ThemeKind::default().graph()
+    }
+}
+
+/// The bundled stylesheets, with the graph colours that go with each
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeKind {
+    /// `style.css` on its own
+    #[default]
+    Glass,
+    DarkGlass,
+    GlassyNeo,
+    Neomorphic,
+}
+
+impl ThemeKind {
+    pub const ALL: [ThemeKind; 4] = [
+        ThemeKind::Glass,
+        ThemeKind::DarkGlass,
+        ThemeKind::GlassyNeo,
+        ThemeKind::Neomorphic,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeKind::Glass => "Glass",
+            ThemeKind::DarkGlass => "Dark glass",
+            ThemeKind::GlassyNeo => "Glassy neo",
+            ThemeKind::Neomorphic => "Neomorphic",
+        }
+    }
+
+    /// Stable key for storage and select values
+    pub fn key(&self) -> &'static str {
+        match self {
+            ThemeKind::Glass => "glass",
+            ThemeKind::DarkGlass => "dark-glass",
+            ThemeKind::GlassyNeo => "glassy-neo",
+            ThemeKind::Neomorphic => "neomorphic",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ThemeKind> {
+        Self::ALL.into_iter().find(|theme| theme.key() == key)
+    }
+
+    /// Stylesheet layered over `style.css`, as served next to `index.html`
+    pub fn stylesheet(&self) -> Option<&'static str> {
+        match self {
+            ThemeKind::Glass => None,
+            ThemeKind::DarkGlass => Some("style-dark-glass.css"),
+            ThemeKind::GlassyNeo => Some("style-glassy-neo.css"),
+            ThemeKind::Neomorphic => Some("style-neomorphic.css"),
+        }
+    }
+
+    pub fn graph(&self) -> GraphTheme {
+        match self {
+            ThemeKind::Glass => GraphTheme {
+                node: "#4A90E2",
+                node_stroke: "white",
+                node_text: "white",
+                node_text_outline: "black",
+                term_text: "#333",
+                edge: "#888888",
+                selected_node: "#FF6B6B",
+                selected_edge: "#FF6B6B",
+                label_text: "#2563eb",
+                label_background: "rgba(255, 255, 255, 0.9)",
+                label_border: "rgba(37, 99, 235, 0.3)",
+                leader: "rgba(37, 99, 235, 0.5)",
+                overlay: "#4A90E2",
+                edge_classes: EDGE_CLASS_COLORS,
+            },
+            ThemeKind::DarkGlass => GraphTheme {
+                node: "#3B6FB6",
+                node_stroke: "#e8ecf1",
+                node_text: "white",
+                node_text_outline: "#1a202c",
+                term_text: "#1a202c",
+                edge: "#4a5568",
+                selected_node: "#F97316",
+                selected_edge: "#F97316",
+                label_text: "#e8ecf1",
+                label_background: "rgba(44, 62, 80, 0.85)",
+                label_border: "rgba(232, 236, 241, 0.3)",
+                leader: "rgba(44, 62, 80, 0.6)",
+                overlay: "#2c3e50",
+                edge_classes: EDGE_CLASS_COLORS,
+            },
+            ThemeKind::GlassyNeo => GraphTheme {
+                node: "#4A90E2",
+                node_stroke: "white",
+                node_text: "white",
+                node_text_outline: "#2c3e50",
+                term_text: "#2c3e50",
+                edge: "#94a3b8",
+                selected_node: "#FF6B6B",
+                selected_edge: "#FF6B6B",
+                label_text: "#4a5568",
+                label_background: "rgba(255, 255, 255, 0.75)",
+                label_border: "rgba(163, 177, 198, 0.5)",
+                leader: "rgba(163, 177, 198, 0.8)",
+                overlay: "#a3b1c6",
+                edge_classes: EDGE_CLASS_COLORS,
+            },
+            ThemeKind::Neomorphic => GraphTheme {
+                node: "#7C8DB5",
+                node_stroke: "#e8ecf1",
+                node_text: "white",
+                node_text_outline: "#4a5568",
+                term_text: "#4a5568",
+                edge: "#a3b1c6",
+                selected_node: "#E76F51",
+                selected_edge: "#E76F51",
+                label_text: "#4a5568",
+                label_background: "#e8ecf1",
+                label_border: "rgba(163, 177, 198, 0.6)",
+                leader: "rgba(163, 177, 198, 0.9)",
+                overlay: "#a3b1c6",
+                edge_classes: EDGE_CLASS_COLORS,
+            },
+        }
+    }
+}
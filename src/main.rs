@@ -1,8 +1,8 @@
-mod app;
 mod api_app;
 mod components;
 mod core;
 mod api;
+mod preferences;
 
 use api_app::ApiApp;
 
@@ -0,0 +1,198 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::api::models::Language;
+use crate::api_app::Breadcrumb;
+use crate::components::api_graph_view::Focus;
+use crate::core::geometry::LayoutKind;
+use crate::core::palette::{Palette, PaletteKind, PaletteMode};
+use crate::core::system_kind::SystemKind;
+use crate::core::theme::ThemeKind;
+
+/// localStorage key for the user's preferences
+const PREFERENCES_KEY: &str = "systematics-preferences";
+
+/// localStorage key for the last session
+const SESSION_KEY: &str = "systematics-session";
+
+/// localStorage key the theme was kept under before preferences were versioned
+const LEGACY_THEME_KEY: &str = "systematics-theme";
+
+/// Current preferences format. Bump it when the format changes and add a step to `upgrade`.
+pub const PREFERENCES_VERSION: u64 = 1;
+
+/// Current session format. Sessions from other versions are dropped rather than migrated.
+pub const SESSION_VERSION: u64 = 1;
+
+/// Settings that persist across reloads. Choices are stored by key so that renaming a
+/// variant doesn't break stored preferences; unknown keys fall back to the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub version: u64,
+    pub show_edge_labels: bool,
+    pub theme: String,
+    pub colour_language: String,
+    pub layout: String,
+    pub palette: String,
+    pub palette_mode: String,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Preferences {
+            version: PREFERENCES_VERSION,
+            show_edge_labels: false,
+            theme: ThemeKind::default().key().to_string(),
+            colour_language: Language::Hex.key().to_string(),
+            layout: LayoutKind::default().key().to_string(),
+            palette: PaletteKind::default().key().to_string(),
+            palette_mode: PaletteMode::default().key().to_string(),
+        }
+    }
+}
+
+impl Preferences {
+    pub fn theme(&self) -> ThemeKind {
+        ThemeKind::from_key(&self.theme).unwrap_or_default()
+    }
+
+    pub fn colour_language(&self) -> Language {
+        Language::from_key(&self.colour_language).unwrap_or(Language::Hex)
+    }
+
+    pub fn layout(&self) -> LayoutKind {
+        LayoutKind::from_key(&self.layout).unwrap_or_default()
+    }
+
+    pub fn palette(&self) -> Palette {
+        Palette {
+            kind: PaletteKind::from_key(&self.palette).unwrap_or_default(),
+            mode: PaletteMode::from_key(&self.palette_mode).unwrap_or_default(),
+        }
+    }
+
+    /// Stored preferences, upgraded from older formats. Defaults if there are none, or if
+    /// they were written by a newer version of the app.
+    pub fn load() -> Preferences {
+        let stored = LocalStorage::get::<Value>(PREFERENCES_KEY).ok().or_else(|| {
+            // Before versioning only the theme was kept, as a bare key
+            let theme = LocalStorage::get::<String>(LEGACY_THEME_KEY).ok()?;
+            Some(serde_json::json!({ "version": 0, "theme": theme }))
+        });
+        let Some(mut value) = stored else {
+            return Preferences::default();
+        };
+
+        let mut version = value.get("version").and_then(Value::as_u64).unwrap_or(0);
+        if version > PREFERENCES_VERSION {
+            return Preferences::default();
+        }
+        let migrated = version < PREFERENCES_VERSION;
+        while version < PREFERENCES_VERSION {
+            value = upgrade(value, version);
+            version += 1;
+        }
+
+        let preferences: Preferences = serde_json::from_value(value).unwrap_or_default();
+        if migrated {
+            preferences.save();
+            LocalStorage::delete(LEGACY_THEME_KEY);
+        }
+        preferences
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(PREFERENCES_KEY, self);
+    }
+}
+
+/// Upgrade stored preferences from `version` to the next version
+fn upgrade(mut value: Value, version: u64) -> Value {
+    match version {
+        // Version 0 held just the theme key; every other setting takes its default
+        0 => {
+            value["version"] = Value::from(1);
+            value
+        }
+        _ => value,
+    }
+}
+
+/// Where the user was when they left: the open system, how they got there and what was selected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub version: u64,
+    pub system: Option<SystemKind>,
+    pub breadcrumbs: Vec<Breadcrumb>,
+    pub selection: Option<Focus>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Session {
+            version: SESSION_VERSION,
+            system: None,
+            breadcrumbs: vec![],
+            selection: None,
+        }
+    }
+}
+
+impl Session {
+    /// The stored session, if there is one in the current format
+    pub fn load() -> Option<Session> {
+        LocalStorage::get::<Session>(SESSION_KEY)
+            .ok()
+            .filter(|session| session.version == SESSION_VERSION)
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(SESSION_KEY, self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgrades_a_bare_theme_from_version_0() {
+        let stored = serde_json::json!({ "version": 0, "theme": "dark-glass" });
+        let preferences: Preferences = serde_json::from_value(upgrade(stored, 0)).unwrap();
+
+        assert_eq!(preferences.version, PREFERENCES_VERSION);
+        assert_eq!(preferences.theme(), ThemeKind::DarkGlass);
+        assert_eq!(preferences.colour_language(), Language::Hex);
+        assert_eq!(preferences.layout(), LayoutKind::default());
+        assert_eq!(preferences.palette(), Palette::default());
+    }
+
+    #[test]
+    fn unknown_keys_fall_back_one_setting_at_a_time() {
+        let stored = serde_json::json!({
+            "version": PREFERENCES_VERSION,
+            "show_edge_labels": true,
+            "theme": "no-such-theme",
+            "colour_language": "klingon",
+            "palette": "greyscale",
+        });
+        let preferences: Preferences = serde_json::from_value(stored).unwrap();
+
+        assert!(preferences.show_edge_labels);
+        assert_eq!(preferences.theme(), ThemeKind::default());
+        assert_eq!(preferences.colour_language(), Language::Hex);
+        assert_eq!(preferences.palette().kind, PaletteKind::Greyscale);
+    }
+
+    #[test]
+    fn colour_language_round_trips_by_key() {
+        for language in Language::ALL {
+            let preferences = Preferences { colour_language: language.key().to_string(), ..Preferences::default() };
+            let stored = serde_json::to_value(&preferences).unwrap();
+            let loaded: Preferences = serde_json::from_value(stored).unwrap();
+            assert_eq!(loaded.colour_language(), language);
+        }
+    }
+}